@@ -0,0 +1,246 @@
+use crate::scalar::Real;
+use crate::vector2::Vector2;
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+
+/// Wraps into (-PI, PI], the range of `signed_angle` and `to_angle`.
+pub(crate) fn wrap_signed<T: Real>(radians: T) -> T {
+    if radians > -T::PI && radians <= T::PI { return radians; }
+    T::PI - (T::PI - radians).rem_euclid(T::PI + T::PI)
+}
+
+/// Wraps into [0, 2PI), the range of `wide_angle`.
+pub(crate) fn wrap_positive<T: Real>(radians: T) -> T {
+    let full_turn = T::PI + T::PI;
+    if radians >= T::ZERO && radians < full_turn { return radians; }
+
+    let wrapped = radians.rem_euclid(full_turn);
+    // A tiny negative angle rounds up to a full turn.
+    if wrapped < full_turn { wrapped } else { T::ZERO }
+}
+
+/// Angle stored in radians, with no implied range until wrapped.
+#[derive(Debug, Default, Copy, Clone, PartialEq, PartialOrd)]
+pub struct Angle<T = f32>(T);
+
+impl<T: Real> Angle<T> {
+    pub fn from_radians(radians: T) -> Self {
+        Angle(radians)
+    }
+
+    pub fn from_degrees(degrees: T) -> Self {
+        Angle(degrees * T::PI / T::from_f64(180.))
+    }
+
+    pub fn radians(&self) -> T {
+        self.0
+    }
+
+    pub fn degrees(&self) -> T {
+        self.0 * T::from_f64(180.) / T::PI
+    }
+
+    /// Same direction in (-PI, PI], as `signed_angle`.
+    pub fn wrapped_signed(&self) -> Self {
+        Angle(wrap_signed(self.0))
+    }
+
+    /// Same direction in [0, 2PI), as `wide_angle`.
+    pub fn wrapped_positive(&self) -> Self {
+        Angle(wrap_positive(self.0))
+    }
+
+    /// Shortest arc from `self` to `other`, in (-PI, PI].
+    pub fn difference(&self, other: &Self) -> Self {
+        Angle(wrap_signed(wrap_signed(other.0) - wrap_signed(self.0)))
+    }
+
+    /// Interpolates along the shortest arc, the result is not wrapped.
+    pub fn lerp(&self, other: &Self, t: T) -> Self {
+        Angle(self.0 + self.difference(other).0 * t)
+    }
+
+    pub fn sin(&self) -> T {
+        self.0.sin()
+    }
+
+    pub fn cos(&self) -> T {
+        self.0.cos()
+    }
+}
+
+impl<T: Real> Add for Angle<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Angle(self.0 + other.0)
+    }
+}
+
+impl<T: Real> AddAssign for Angle<T> {
+    fn add_assign(&mut self, other: Self) {
+        self.0 += other.0;
+    }
+}
+
+impl<T: Real> Sub for Angle<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Angle(self.0 - other.0)
+    }
+}
+
+impl<T: Real> SubAssign for Angle<T> {
+    fn sub_assign(&mut self, other: Self) {
+        self.0 -= other.0;
+    }
+}
+
+impl<T: Real> Mul<T> for Angle<T> {
+    type Output = Self;
+
+    fn mul(self, k: T) -> Self {
+        Angle(self.0 * k)
+    }
+}
+
+impl<T: Real> Div<T> for Angle<T> {
+    type Output = Self;
+
+    fn div(self, k: T) -> Self {
+        Angle(self.0 / k)
+    }
+}
+
+impl<T: Real> Neg for Angle<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Angle(-self.0)
+    }
+}
+
+//  Angle returning variants of the bare radian APIs, named after their range.
+impl<T: Real, S> Vector2<T, S> {
+    /// `angle` : unsigned, in [0, PI].
+    pub fn angle_between(&self, other: &Self) -> Angle<T> {
+        Angle(self.angle(other))
+    }
+
+    /// `signed_angle` : counter-clockwise from `self` to `other`, in (-PI, PI].
+    pub fn signed_angle_to(&self, other: &Self) -> Angle<T> {
+        Angle(self.signed_angle(other))
+    }
+
+    /// `wide_angle` : counter-clockwise from `self` to `other`, in [0, 2PI).
+    pub fn wide_angle_to(&self, other: &Self) -> Angle<T> {
+        Angle(wrap_positive(self.wide_angle(other)))
+    }
+
+    /// `to_angle` : counter-clockwise from the x axis, in (-PI, PI].
+    pub fn heading(&self) -> Angle<T> {
+        Angle(self.to_angle())
+    }
+
+    pub fn rotated_by(&self, angle: Angle<T>) -> Self {
+        self.rotated(angle.radians())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    //  _________________
+    //
+    //  TESTS OVER ANGLES
+    //  _________________
+    //  Angle Implements :
+    //  - from_radians, from_degrees, radians, degrees
+    //  - wrapped_signed    (Self) -> Self in (-PI, PI]
+    //  - wrapped_positive  (Self) -> Self in [0, 2PI)
+    //  - difference        (Self, Self) -> Self, shortest arc
+    //  - lerp              (Self, Self, f32) -> Self, shortest arc
+    //  - Add, Sub, Neg, Mul<f32>, Div<f32>
+    //  Vector2 Implements :
+    //  - angle_between, signed_angle_to, wide_angle_to  (Self, Self) -> Angle
+    //  - heading           (Self) -> Angle
+    //  - rotated_by        (Self, Angle) -> Self
+
+    use super::Angle;
+    use crate::vector2::Vector2;
+    use crate::test_tools;
+    use std::f32::consts::PI;
+
+    #[test]
+    fn angle_should_convert_degrees_and_radians() {
+        assert_eq!(PI, Angle::from_degrees(180.).radians());
+        assert_eq!(90., Angle::from_radians(PI/2.).degrees());
+        test_tools::assert_approx_eq!(-45., &Angle::from_radians(-PI/4.).degrees());
+    }
+
+    #[test]
+    fn angle_should_implement_wrapped_signed() {
+        assert_eq!(Angle::from_radians(PI), Angle::from_radians(-PI).wrapped_signed());
+        assert_eq!(Angle::from_radians(PI), Angle::from_radians(PI).wrapped_signed());
+        assert_eq!(Angle::from_radians(-PI/2.), Angle::from_radians(-PI/2.).wrapped_signed());
+        test_tools::assert_approx_eq!(-90., &Angle::from_degrees(270.).wrapped_signed().degrees());
+        test_tools::assert_approx_eq!(10., &Angle::from_degrees(730.).wrapped_signed().degrees());
+    }
+
+    #[test]
+    fn angle_should_implement_wrapped_positive() {
+        assert_eq!(Angle::from_radians(0.), Angle::from_radians(0.).wrapped_positive());
+        assert_eq!(Angle::from_radians(3.*PI/2.), Angle::from_radians(-PI/2.).wrapped_positive());
+        assert_eq!(Angle::from_radians(0.), Angle::<f32>::from_radians(-1e-9).wrapped_positive());
+        test_tools::assert_approx_eq!(10., &Angle::from_degrees(370.).wrapped_positive().degrees());
+    }
+
+    #[test]
+    fn angle_should_implement_difference() {
+        let angle1 = Angle::from_degrees(170.);
+        let angle2 = Angle::from_degrees(-170.);
+        test_tools::assert_approx_eq!(20., &angle1.difference(&angle2).degrees());
+        test_tools::assert_approx_eq!(-20., &angle2.difference(&angle1).degrees());
+
+        let angle1 = Angle::from_degrees(10.);
+        test_tools::assert_approx_eq!(-20., &angle1.difference(&Angle::from_degrees(350.)).degrees());
+    }
+
+    #[test]
+    fn angle_should_implement_lerp() {
+        let angle1 = Angle::from_degrees(170.);
+        let angle2 = Angle::from_degrees(-170.);
+        test_tools::assert_approx_eq!(180., &angle1.lerp(&angle2, 0.5).degrees());
+        assert_eq!(angle1, angle1.lerp(&angle2, 0.));
+    }
+
+    #[test]
+    fn angle_should_implement_operators() {
+        let angle1 = Angle::from_radians(1.);
+        let mut angle2 = Angle::from_radians(0.5);
+        assert_eq!(Angle::from_radians(1.5), angle1 + angle2);
+        assert_eq!(Angle::from_radians(0.5), angle1 - angle2);
+        assert_eq!(Angle::from_radians(-1.), -angle1);
+        assert_eq!(Angle::from_radians(2.), angle1 * 2.);
+        assert_eq!(Angle::from_radians(0.25), angle2 / 2.);
+        angle2 += angle1;
+        angle2 -= Angle::from_radians(0.25);
+        assert_eq!(Angle::from_radians(1.25), angle2);
+        assert!(angle1 > angle2 - angle1);
+    }
+
+    #[test]
+    fn vector2_should_implement_angle_variants() {
+        let vec1 = Vector2::new(1., 0.);
+        let vec2 = Vector2::new(0., -1.);
+        assert_eq!(Angle::from_radians(PI/2.), vec1.angle_between(&vec2));
+        assert_eq!(Angle::from_radians(-PI/2.), vec1.signed_angle_to(&vec2));
+        assert_eq!(Angle::from_radians(3.*PI/2.), vec1.wide_angle_to(&vec2));
+        assert_eq!(Angle::from_radians(-PI/2.), vec2.heading());
+        assert_eq!(vec1.signed_angle_to(&vec2).wrapped_positive(), vec1.wide_angle_to(&vec2));
+    }
+
+    #[test]
+    fn vector2_should_implement_rotated_by() {
+        assert_eq!(Vector2::new(0., 1.), Vector2::new(1., 0.).rotated_by(Angle::from_degrees(90.)));
+    }
+}
@@ -0,0 +1,296 @@
+use crate::fixed::Fixed;
+use crate::point2::Point2;
+use crate::scalar::{Real, Scalar};
+use crate::unit_vector2::UnitVector2;
+use crate::vector2::Vector2;
+use std::fmt::Debug;
+
+/// How far apart two values may be and still compare equal.
+/// NaN is never approximately equal to anything.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Tolerance<E> {
+    /// `|a - b| <= epsilon`
+    Absolute(E),
+    /// `|a - b| <= epsilon * max(|a|, |b|)`, never passes against an exact zero.
+    Relative(E),
+    /// At most `n` representable values between `a` and `b`.
+    Ulps(u32),
+}
+
+pub trait ApproxEq {
+    type Epsilon: Copy + Debug;
+
+    /// Tolerance of `assert_vec_approx_eq!` when none is given.
+    const DEFAULT_TOLERANCE: Tolerance<Self::Epsilon>;
+
+    fn approx_eq(&self, other: &Self, tolerance: Tolerance<Self::Epsilon>) -> bool;
+
+    /// Name of the first component out of tolerance, `None` when approximately equal.
+    fn approx_mismatch(&self, other: &Self, tolerance: Tolerance<Self::Epsilon>) -> Option<&'static str> {
+        if self.approx_eq(other, tolerance) { None } else { Some("value") }
+    }
+}
+
+macro_rules! impl_approx_eq_float {
+    ($t: ident, $bits: ident, $default: expr) => {
+        impl ApproxEq for $t {
+            type Epsilon = $t;
+
+            const DEFAULT_TOLERANCE: Tolerance<$t> = Tolerance::Absolute($default);
+
+            fn approx_eq(&self, other: &Self, tolerance: Tolerance<$t>) -> bool {
+                if self.is_nan() || other.is_nan() { return false; }
+                if self == other { return true; }
+
+                match tolerance {
+                    Tolerance::Absolute(epsilon) => (self - other).abs() <= epsilon,
+                    Tolerance::Relative(epsilon) => (self - other).abs() <= epsilon * self.abs().max(other.abs()),
+                    Tolerance::Ulps(n) => {
+                        if self.is_sign_negative() != other.is_sign_negative() { return false; }
+                        (self.to_bits() as $bits).abs_diff(other.to_bits() as $bits) <= n as _
+                    }
+                }
+            }
+        }
+    };
+}
+
+impl_approx_eq_float!(f32, i32, 1e-4);
+impl_approx_eq_float!(f64, i64, 1e-9);
+
+/// One ulp of `Fixed` is its resolution, 2^-16.
+impl ApproxEq for Fixed {
+    type Epsilon = Fixed;
+
+    const DEFAULT_TOLERANCE: Tolerance<Fixed> = Tolerance::Ulps(1);
+
+    fn approx_eq(&self, other: &Self, tolerance: Tolerance<Fixed>) -> bool {
+        if self == other { return true; }
+
+        // Compared on the raw bits, widened so that neither the difference nor the bound overflows.
+        // A negative epsilon accepts no difference, as for floats.
+        let diff = self.to_bits().abs_diff(other.to_bits());
+        match tolerance {
+            Tolerance::Absolute(epsilon) => epsilon.to_bits() >= 0 && diff <= epsilon.to_bits().unsigned_abs(),
+            Tolerance::Relative(epsilon) => {
+                let largest = self.to_bits().unsigned_abs().max(other.to_bits().unsigned_abs());
+                epsilon.to_bits() >= 0 && diff as u64 <= (epsilon.to_bits().unsigned_abs() as u64 * largest as u64) >> 16
+            }
+            Tolerance::Ulps(n) => diff <= n,
+        }
+    }
+}
+
+impl<T: Scalar + ApproxEq, S> ApproxEq for Vector2<T, S> {
+    type Epsilon = T::Epsilon;
+
+    const DEFAULT_TOLERANCE: Tolerance<T::Epsilon> = T::DEFAULT_TOLERANCE;
+
+    fn approx_eq(&self, other: &Self, tolerance: Tolerance<T::Epsilon>) -> bool {
+        self.approx_mismatch(other, tolerance).is_none()
+    }
+
+    /// Components are compared one by one, `Relative` is relative to each component.
+    fn approx_mismatch(&self, other: &Self, tolerance: Tolerance<T::Epsilon>) -> Option<&'static str> {
+        if !self.x().approx_eq(&other.x(), tolerance) { return Some("x"); }
+        if !self.y().approx_eq(&other.y(), tolerance) { return Some("y"); }
+        None
+    }
+}
+
+impl<T: Scalar + ApproxEq, S> ApproxEq for Point2<T, S> {
+    type Epsilon = T::Epsilon;
+
+    const DEFAULT_TOLERANCE: Tolerance<T::Epsilon> = T::DEFAULT_TOLERANCE;
+
+    fn approx_eq(&self, other: &Self, tolerance: Tolerance<T::Epsilon>) -> bool {
+        self.to_vector().approx_eq(&other.to_vector(), tolerance)
+    }
+
+    fn approx_mismatch(&self, other: &Self, tolerance: Tolerance<T::Epsilon>) -> Option<&'static str> {
+        self.to_vector().approx_mismatch(&other.to_vector(), tolerance)
+    }
+}
+
+impl<T: Real + ApproxEq, S> ApproxEq for UnitVector2<T, S> {
+    type Epsilon = T::Epsilon;
+
+    const DEFAULT_TOLERANCE: Tolerance<T::Epsilon> = T::DEFAULT_TOLERANCE;
+
+    fn approx_eq(&self, other: &Self, tolerance: Tolerance<T::Epsilon>) -> bool {
+        self.to_vector().approx_eq(&other.to_vector(), tolerance)
+    }
+
+    fn approx_mismatch(&self, other: &Self, tolerance: Tolerance<T::Epsilon>) -> Option<&'static str> {
+        self.to_vector().approx_mismatch(&other.to_vector(), tolerance)
+    }
+}
+
+#[doc(hidden)]
+pub fn default_tolerance<A: ApproxEq>(_: &A) -> Tolerance<A::Epsilon> {
+    A::DEFAULT_TOLERANCE
+}
+
+#[doc(hidden)]
+#[track_caller]
+pub fn assert_approx_eq<A: ApproxEq + Debug>(expected: &A, actual: &A, tolerance: Tolerance<A::Epsilon>) {
+    if let Some(component) = expected.approx_mismatch(actual, tolerance) {
+        panic!(
+            "Values are not approximately equal : component `{}` is out of tolerance {:?}\n-->\texpected : {:?}\n-->\tactual   : {:?}",
+            component, tolerance, expected, actual
+        );
+    }
+}
+
+#[doc(hidden)]
+#[track_caller]
+pub fn assert_approx_ne<A: ApproxEq + Debug>(expected: &A, actual: &A, tolerance: Tolerance<A::Epsilon>) {
+    if expected.approx_eq(actual, tolerance) {
+        panic!(
+            "Values are approximately equal within tolerance {:?}\n-->\texpected : {:?}\n-->\tactual   : {:?}",
+            tolerance, expected, actual
+        );
+    }
+}
+
+/// Asserts two `ApproxEq` values are approximately equal,
+/// printing both values and the first failing component otherwise.
+///
+/// ```
+/// use vector2::assert_vec_approx_eq;
+/// use vector2::vector2::Vector2;
+///
+/// let vec1 = Vector2::new(0.1 + 0.2, 1.);
+/// assert_vec_approx_eq!(Vector2::new(0.3, 1.), vec1);
+/// assert_vec_approx_eq!(Vector2::new(0.3, 1.), vec1, abs = 1e-6);
+/// assert_vec_approx_eq!(Vector2::new(0.3, 1.), vec1, rel = 1e-6);
+/// assert_vec_approx_eq!(Vector2::new(0.3, 1.), vec1, ulps = 2);
+/// ```
+#[macro_export]
+macro_rules! assert_vec_approx_eq {
+    ($expected: expr, $actual: expr $(,)?) => {{
+        let (expected, actual) = (&$expected, &$actual);
+        $crate::approx::assert_approx_eq(expected, actual, $crate::approx::default_tolerance(expected));
+    }};
+    ($expected: expr, $actual: expr, abs = $epsilon: expr $(,)?) => {
+        $crate::approx::assert_approx_eq(&$expected, &$actual, $crate::approx::Tolerance::Absolute($epsilon))
+    };
+    ($expected: expr, $actual: expr, rel = $epsilon: expr $(,)?) => {
+        $crate::approx::assert_approx_eq(&$expected, &$actual, $crate::approx::Tolerance::Relative($epsilon))
+    };
+    ($expected: expr, $actual: expr, ulps = $n: expr $(,)?) => {
+        $crate::approx::assert_approx_eq(&$expected, &$actual, $crate::approx::Tolerance::Ulps($n))
+    };
+}
+
+/// Opposite of `assert_vec_approx_eq!`, with the same tolerance forms.
+#[macro_export]
+macro_rules! assert_vec_approx_ne {
+    ($expected: expr, $actual: expr $(,)?) => {{
+        let (expected, actual) = (&$expected, &$actual);
+        $crate::approx::assert_approx_ne(expected, actual, $crate::approx::default_tolerance(expected));
+    }};
+    ($expected: expr, $actual: expr, abs = $epsilon: expr $(,)?) => {
+        $crate::approx::assert_approx_ne(&$expected, &$actual, $crate::approx::Tolerance::Absolute($epsilon))
+    };
+    ($expected: expr, $actual: expr, rel = $epsilon: expr $(,)?) => {
+        $crate::approx::assert_approx_ne(&$expected, &$actual, $crate::approx::Tolerance::Relative($epsilon))
+    };
+    ($expected: expr, $actual: expr, ulps = $n: expr $(,)?) => {
+        $crate::approx::assert_approx_ne(&$expected, &$actual, $crate::approx::Tolerance::Ulps($n))
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    //  ________________________________
+    //
+    //  TESTS OVER APPROXIMATE EQUALITY
+    //  ________________________________
+    //  f32, f64, Fixed, Vector2, Point2 and UnitVector2 Implement ApproxEq :
+    //  - approx_eq         (Self, Self, Tolerance) -> bool
+    //  - approx_mismatch   (Self, Self, Tolerance) -> Option<component>
+    //  Tolerance :
+    //  - Absolute, Relative, Ulps
+    //  Exported macros :
+    //  - assert_vec_approx_eq!, assert_vec_approx_ne!
+
+    use super::{ApproxEq, Tolerance};
+    use crate::fixed::Fixed;
+    use crate::point2::Point2;
+    use crate::vector2::Vector2;
+
+    #[test]
+    fn f32_should_implement_absolute_tolerance() {
+        assert!(1_f32.approx_eq(&1.00005, Tolerance::Absolute(1e-4)));
+        assert!(!1_f32.approx_eq(&1.001, Tolerance::Absolute(1e-4)));
+        assert!(f32::INFINITY.approx_eq(&f32::INFINITY, Tolerance::Absolute(0.)));
+        assert!(!f32::NAN.approx_eq(&f32::NAN, Tolerance::Absolute(f32::INFINITY)));
+    }
+
+    #[test]
+    fn f32_should_implement_relative_tolerance() {
+        assert!(1e6_f32.approx_eq(&1.00001e6, Tolerance::Relative(1e-4)));
+        assert!(!1e-6_f32.approx_eq(&2e-6, Tolerance::Relative(1e-4)));
+        assert!(!0_f32.approx_eq(&1e-30, Tolerance::Relative(1e-4)));
+    }
+
+    #[test]
+    fn f32_should_implement_ulps_tolerance() {
+        let next = f32::from_bits(1_f32.to_bits() + 1);
+        assert!(1_f32.approx_eq(&next, Tolerance::Ulps(1)));
+        assert!(!1_f32.approx_eq(&next, Tolerance::Ulps(0)));
+        assert!(0_f32.approx_eq(&-0., Tolerance::Ulps(0)));
+        assert!(!f32::MIN_POSITIVE.approx_eq(&-f32::MIN_POSITIVE, Tolerance::Ulps(u32::MAX)));
+        assert!((0.1_f64 + 0.2).approx_eq(&0.3, Tolerance::Ulps(1)));
+    }
+
+    #[test]
+    fn fixed_should_implement_approx_eq() {
+        let fixed1 = Fixed::from_bits(65_536);
+        assert!(fixed1.approx_eq(&Fixed::from_bits(65_537), Tolerance::Ulps(1)));
+        assert!(!fixed1.approx_eq(&Fixed::from_bits(65_538), Tolerance::Ulps(1)));
+        assert!(fixed1.approx_eq(&Fixed::from_bits(65_538), Tolerance::Absolute(Fixed::from_bits(2))));
+        assert!(!fixed1.approx_eq(&Fixed::from_bits(65_538), Tolerance::Absolute(Fixed::from_bits(-2))));
+        assert!(!fixed1.approx_eq(&Fixed::from_bits(65_538), Tolerance::Absolute(Fixed::from_bits(i32::MIN))));
+        assert!(fixed1.approx_eq(&fixed1, Tolerance::Absolute(Fixed::from_bits(-2))));
+    }
+
+    #[test]
+    fn fixed_relative_tolerance_should_not_overflow() {
+        let epsilon = Fixed::from_bits(655); // About 0.01
+        let fixed1 = Fixed::from_int(20_000);
+        assert!(!Fixed::from_int(-20_000).approx_eq(&fixed1, Tolerance::Relative(epsilon)));
+        assert!(!Fixed::from_bits(i32::MIN).approx_eq(&Fixed::from_bits(i32::MAX), Tolerance::Relative(epsilon)));
+        assert!(Fixed::from_int(19_900).approx_eq(&fixed1, Tolerance::Relative(epsilon)));
+        assert!(!Fixed::from_int(19_700).approx_eq(&fixed1, Tolerance::Relative(epsilon)));
+        assert!(Fixed::from_int(-20_000).approx_eq(&fixed1, Tolerance::Relative(Fixed::from_int(2))));
+        assert!(!Fixed::from_int(19_900).approx_eq(&fixed1, Tolerance::Relative(-epsilon)));
+    }
+
+    #[test]
+    fn vector2_should_implement_approx_mismatch() {
+        let vec1 = Vector2::new(1., 2.);
+        assert_eq!(None, vec1.approx_mismatch(&Vector2::new(1.00001, 2.), Tolerance::Absolute(1e-4)));
+        assert_eq!(Some("y"), vec1.approx_mismatch(&Vector2::new(1., 2.1), Tolerance::Absolute(1e-4)));
+        assert_eq!(Some("x"), vec1.approx_mismatch(&Vector2::new(1.1, 2.1), Tolerance::Absolute(1e-4)));
+    }
+
+    #[test]
+    fn assert_vec_approx_eq_should_accept_every_tolerance() {
+        let vec1 = Vector2::new(0.1 + 0.2, 3.);
+        assert_vec_approx_eq!(Vector2::new(0.3, 3.), vec1);
+        assert_vec_approx_eq!(Vector2::new(0.3, 3.), vec1, abs = 1e-7);
+        assert_vec_approx_eq!(Vector2::new(0.3, 3.), vec1, rel = 1e-7);
+        assert_vec_approx_eq!(Vector2::new(0.3, 3.), vec1, ulps = 1);
+        assert_vec_approx_eq!(Point2::<f32>::new(1., 1.), Point2::new(1., 1.00001));
+        assert_vec_approx_ne!(Vector2::new(0.3, 3.), Vector2::new(0.3, 3.1));
+        assert_vec_approx_ne!(Vector2::new(1_f64, 0.), Vector2::new(1.001, 0.), rel = 1e-6);
+    }
+
+    #[test]
+    #[should_panic(expected = "component `y`")]
+    fn assert_vec_approx_eq_should_report_failing_component() {
+        assert_vec_approx_eq!(Vector2::new(1., 2.), Vector2::new(1., 2.5));
+    }
+}
@@ -1,38 +1,169 @@
 pub mod extended_ops {
     use crate::vector2::Vector2;
+    use crate::vector3::Vector3;
+    use crate::vector4::Vector4;
+    use crate::scalar::Scalar;
 
-    impl Vector2 {
-        pub fn dot(&self, other: &Self) -> f32 {
-            self.x() * other.x() + self.y() * other.y()
+    impl<T: Scalar, S> Vector2<T, S> {
+        pub fn dot(&self, other: &Self) -> T {
+            T::dot2(self.x(), self.y(), other.x(), other.y())
         }
         
-        pub fn determinent(&self, other: &Self) -> f32 {
-            self.x() * other.y() - self.y() * other.x()
+        pub fn determinent(&self, other: &Self) -> T {
+            T::det2(self.x(), self.y(), other.x(), other.y())
+        }
+    }
+
+    impl<T: Scalar> Vector3<T> {
+        pub fn dot(&self, other: &Self) -> T {
+            self.x() * other.x() + self.y() * other.y() + self.z() * other.z()
+        }
+
+        pub fn cross(&self, other: &Self) -> Self {
+            Vector3::new(
+                self.y() * other.z() - self.z() * other.y(),
+                self.z() * other.x() - self.x() * other.z(),
+                self.x() * other.y() - self.y() * other.x()
+            )
+        }
+    }
+
+    impl<T: Scalar> Vector4<T> {
+        pub fn dot(&self, other: &Self) -> T {
+            self.x() * other.x() + self.y() * other.y() + self.z() * other.z() + self.w() * other.w()
         }
     }
 }
 
 pub mod geometry {
     use crate::vector2::Vector2;
+    use crate::unit_vector2::UnitVector2;
+    use crate::vector3::Vector3;
+    use crate::vector4::Vector4;
+    use crate::scalar::Real;
 
-    impl Vector2 {
+    /// Rounding can push a cosine past ±1, where `acos` is NaN.
+    fn clamped_acos<T: Real>(cos: T) -> T {
+        if cos > T::ONE { T::ZERO } else if cos < -T::ONE { T::PI } else { cos.acos() }
+    }
+
+    /// Largest component magnitude, angles are computed on vectors divided by it
+    /// so that their products can neither overflow nor underflow.
+    fn largest<T: Real>(components: &[T]) -> T {
+        components.iter().fold(T::ZERO, |largest, c| if c.abs() > largest { c.abs() } else { largest })
+    }
+
+    impl<T: Real, S> Vector2<T, S> {
         pub fn normalized(&self) -> Self {
-            if self.magnitude() == 0. { Vector2::new(0., 0.) }
-            else { Vector2::new(self.x()/self.magnitude(), self.y()/self.magnitude()) } 
+            let magnitude = self.magnitude();
+            if magnitude == T::ZERO { Vector2::in_space(T::ZERO, T::ZERO) }
+            else { Vector2::in_space(self.x()/magnitude, self.y()/magnitude) } 
+        }
+
+        /// `None` for a zero length vector, where `normalized` returns (0, 0),
+        /// and for non-finite components, which have no direction to keep.
+        /// Scaled down by the largest component first, so that the length cannot overflow.
+        pub fn try_normalize(&self) -> Option<UnitVector2<T, S>> {
+            let (x, y) = (self.x().abs(), self.y().abs());
+            let scale = if x > y { x } else { y };
+            if scale == T::ZERO { return None; }
+
+            // Non-finite components leave a NaN magnitude, which fails the comparison.
+            let scaled = *self / scale;
+            let magnitude = scaled.magnitude();
+            (magnitude > T::ZERO).then(|| UnitVector2::new_unchecked(scaled / magnitude))
         }
         
-        pub fn angle(&self, other: &Self) -> f32 {
-            let mg_base = self.magnitude() * other.magnitude();
-            if mg_base == 0. { return 0.; }
-            
-            (self.dot(other)/mg_base).acos()
+        pub fn angle(&self, other: &Self) -> T {
+            self.signed_angle(other).abs()
         }
         
-        pub fn signed_angle(&self, other: &Self) -> f32 {
-            let mg_base = self.magnitude() * other.magnitude();
-            if mg_base == 0. { return 0.; }
+        /// Zero when either vector is zero. No magnitude is computed, so that neither
+        /// overflow nor underflow of the squared lengths can skew the result.
+        pub fn signed_angle(&self, other: &Self) -> T {
+            let zero = Vector2::in_space(T::ZERO, T::ZERO);
+            if *self == zero || *other == zero { return T::ZERO; }
         
-            (self.x()*other.y() - self.y()*other.x()).atan2(self.x()*other.x() + self.y()*other.y())
+            T::angle2(self.x(), self.y(), other.x(), other.y())
+        }
+
+        /// `None` onto a zero length vector, which has no direction to project on.
+        pub fn projected_on(&self, other: &Self) -> Option<Self> {
+            let mg_base = other.magnitude_squared();
+            if mg_base == T::ZERO { return None; }
+
+            Some(*other * (self.dot(other) / mg_base))
+        }
+
+        /// What remains of `self` once projected on `other`, `None` from a zero length vector.
+        pub fn rejected_from(&self, other: &Self) -> Option<Self> {
+            self.projected_on(other).map(|projection| *self - projection)
+        }
+
+        pub fn projected_on_axis(&self, axis: &UnitVector2<T, S>) -> Self {
+            axis.to_vector() * self.dot(axis.as_vector())
+        }
+
+        pub fn rejected_from_axis(&self, axis: &UnitVector2<T, S>) -> Self {
+            *self - self.projected_on_axis(axis)
+        }
+
+        /// Bounces off a surface of the given normal, either side of the surface.
+        pub fn reflected(&self, normal: &UnitVector2<T, S>) -> Self {
+            let normal = normal.to_vector();
+            *self - normal * ((T::ONE + T::ONE) * self.dot(&normal))
+        }
+
+        /// Crosses a surface of the given normal, facing the incident side, `eta` being the
+        /// ratio of refractive indices (from / to). The length of `self` is kept.
+        /// `None` on total internal reflection.
+        pub fn refracted(&self, normal: &UnitVector2<T, S>, eta: T) -> Option<Self> {
+            let magnitude = self.magnitude();
+            if magnitude == T::ZERO { return Some(*self); }
+
+            let incident = *self / magnitude;
+            let normal = normal.to_vector();
+            let cos = incident.dot(&normal);
+            let k = T::ONE - eta * eta * (T::ONE - cos * cos);
+            if k < T::ZERO { return None; }
+
+            Some((incident * eta - normal * (eta * cos + k.sqrt())) * magnitude)
+        }
+    }
+
+    impl<T: Real> Vector3<T> {
+        pub fn normalized(&self) -> Self {
+            let magnitude = self.magnitude();
+            if magnitude == T::ZERO { Vector3::new(T::ZERO, T::ZERO, T::ZERO) }
+            else { Vector3::new(self.x()/magnitude, self.y()/magnitude, self.z()/magnitude) }
+        }
+
+        /// Zero when either vector is zero.
+        pub fn angle(&self, other: &Self) -> T {
+            let (scale1, scale2) = (largest(&[self.x(), self.y(), self.z()]), largest(&[other.x(), other.y(), other.z()]));
+            if scale1 == T::ZERO || scale2 == T::ZERO { return T::ZERO; }
+
+            let vec1 = Vector3::new(self.x()/scale1, self.y()/scale1, self.z()/scale1);
+            let vec2 = Vector3::new(other.x()/scale2, other.y()/scale2, other.z()/scale2);
+            vec1.cross(&vec2).magnitude().atan2(vec1.dot(&vec2))
+        }
+    }
+
+    impl<T: Real> Vector4<T> {
+        pub fn normalized(&self) -> Self {
+            let magnitude = self.magnitude();
+            if magnitude == T::ZERO { Vector4::new(T::ZERO, T::ZERO, T::ZERO, T::ZERO) }
+            else { Vector4::new(self.x()/magnitude, self.y()/magnitude, self.z()/magnitude, self.w()/magnitude) }
+        }
+
+        /// Zero when either vector is zero.
+        pub fn angle(&self, other: &Self) -> T {
+            let (scale1, scale2) = (largest(&[self.x(), self.y(), self.z(), self.w()]), largest(&[other.x(), other.y(), other.z(), other.w()]));
+            if scale1 == T::ZERO || scale2 == T::ZERO { return T::ZERO; }
+
+            let vec1 = Vector4::new(self.x()/scale1, self.y()/scale1, self.z()/scale1, self.w()/scale1);
+            let vec2 = Vector4::new(other.x()/scale2, other.y()/scale2, other.z()/scale2, other.w()/scale2);
+            clamped_acos(vec1.dot(&vec2) / (vec1.magnitude() * vec2.magnitude()))
         }
     }
 }
@@ -46,13 +177,24 @@ mod tests {
     //  _____________________
     //  Vector2 Implements :
     //  - normalized        (Self) -> Self
+    //  - try_normalize     (Self) -> Option<UnitVector2>
     //  - dot_product       (Self, Self) -> f32
     //  - determinent       (Self, Self) -> f32
     //  - angle             (Self, Self) -> f32
     //  - signed_angle      (Self, Self) -> f32
-    //  - projected_on      (Self, Self) -> Self
+    //  - projected_on      (Self, Self) -> Option<Self>
+    //  - rejected_from     (Self, Self) -> Option<Self>
+    //  - projected_on_axis, rejected_from_axis (Self, UnitVector2) -> Self
+    //  - reflected         (Self, UnitVector2) -> Self
+    //  - refracted         (Self, UnitVector2, f32) -> Option<Self>
+    //  Vector3 and Vector4 Implement :
+    //  - normalized, dot_product, angle
+    //  - cross             (Vector3, Vector3) -> Vector3
     
     use crate::vector2::Vector2;
+    use crate::unit_vector2::UnitVector2;
+    use crate::vector3::Vector3;
+    use crate::vector4::Vector4;
     use std::f32::consts::PI;
     use crate::test_tools;
 
@@ -77,6 +219,35 @@ mod tests {
         test_tools::assert_approx_eq!(1., &vec1.magnitude());
     }
 
+    #[test]
+    fn vector2_should_implement_try_normalize() {
+        let unit1 = Vector2::new(950., 0.).try_normalize().unwrap();
+        assert_eq!(Vector2::new(1., 0.), unit1.to_vector());
+
+        let unit1 = Vector2::new(26.2, 12.5).try_normalize().unwrap();
+        test_tools::assert_approx_eq!(1., &unit1.as_vector().magnitude());
+        assert_eq!(Vector2::new(26.2, 12.5).normalized(), unit1.to_vector());
+
+        assert_eq!(None, Vector2::new(0., 0.).try_normalize());
+    }
+
+    #[test]
+    fn vector2_try_normalize_should_return_unit_vectors_at_extreme_magnitudes() {
+        let unit1 = Vector2::new(3e38, 3e38).try_normalize().unwrap();
+        test_tools::assert_approx_eq!(1., &unit1.as_vector().magnitude());
+        test_tools::assert_approx_eq!(unit1.x(), &unit1.y());
+
+        let unit1 = Vector2::new(1e-40, 0.).try_normalize().unwrap();
+        assert_eq!(Vector2::new(1., 0.), unit1.to_vector());
+    }
+
+    #[test]
+    #[cfg(not(feature = "validate"))]
+    fn vector2_try_normalize_should_reject_non_finite_vectors() {
+        assert_eq!(None, Vector2::new(f32::INFINITY, 0.).try_normalize());
+        assert_eq!(None, Vector2::new(f32::NAN, 1.).try_normalize());
+    }
+
     fn test_dot_product_procedure(vec1: Vector2, vec2: Vector2, expected_prod: f32) {
         let vec1prod = vec1.dot(&vec2);
         let vec2prod = vec2.dot(&vec1);
@@ -140,6 +311,20 @@ mod tests {
         test_tools::assert_approx_eq!(PI/4., &vec1.angle(&vec2));
     }
 
+    #[test]
+    fn vector2_angles_should_hold_at_large_and_small_magnitudes() {
+        let vec1 = Vector2::new(1e10, 0.);
+        assert_eq!(0., vec1.angle(&vec1));
+        assert_eq!(0., vec1.signed_angle(&vec1));
+        assert_eq!(PI/2., vec1.angle(&Vector2::new(0., 1e10)));
+
+        let vec1 = Vector2::new(1e-12, 0.);
+        let vec2 = Vector2::new(0., 1e-12);
+        assert_eq!(PI/2., vec1.angle(&vec2));
+        assert_eq!(PI/2., vec1.signed_angle(&vec2));
+        assert_eq!(-PI/2., vec2.signed_angle(&vec1));
+    }
+
     #[test]
     fn vector2_should_implement_signed_angle() {
         let vec1 = Vector2::new(1., 0.);
@@ -152,4 +337,161 @@ mod tests {
         let vec2 = Vector2::new(-1., 0.);
         assert_eq!(PI, vec1.signed_angle(&vec2));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn vector2_geometry_should_support_f64() {
+        use std::f64::consts::PI;
+
+        let vec1 = Vector2::new(3_f64, 4.);
+        assert_eq!(Vector2::new(0.6, 0.8), vec1.normalized());
+        assert_eq!(25., vec1.dot(&vec1));
+
+        let vec1 = Vector2::new(1_f64, 0.);
+        let vec2 = Vector2::new(0_f64, -1.);
+        assert_eq!(PI/2., vec1.angle(&vec2));
+        assert_eq!(-PI/2., vec1.signed_angle(&vec2));
+    }
+
+    #[test]
+    fn vector3_should_implement_dot_product() {
+        let vec1 = Vector3::new(1., 2., 3.);
+        let vec2 = Vector3::new(4., -5., 6.);
+        assert_eq!(12., vec1.dot(&vec2));
+        assert_eq!(vec1.dot(&vec2), vec2.dot(&vec1));
+    }
+
+    #[test]
+    fn vector3_should_implement_cross() {
+        let x = Vector3::new(1., 0., 0.);
+        let y = Vector3::new(0., 1., 0.);
+        let z = Vector3::new(0., 0., 1.);
+        assert_eq!(z, x.cross(&y));
+        assert_eq!(x, y.cross(&z));
+        assert_eq!(y, z.cross(&x));
+        assert_eq!(Vector3::new(0., 0., -1.), y.cross(&x));
+
+        let vec1 = Vector3::new(1., 2., 3.);
+        let vec2 = Vector3::new(4., -5., 6.);
+        let cross = vec1.cross(&vec2);
+        assert_eq!(Vector3::new(27., 6., -13.), cross);
+        assert_eq!(0., cross.dot(&vec1));
+        assert_eq!(0., cross.dot(&vec2));
+    }
+
+    #[test]
+    fn vector3_should_implement_normalized() {
+        let vec1 = Vector3::new(2., 3., 6.).normalized();
+        test_tools::assert_approx_eq!(1., &vec1.magnitude());
+        test_tools::assert_approx_eq!(2./7., &vec1.x());
+        assert_eq!(Vector3::new(0., 0., 0.), Vector3::new(0., 0., 0.).normalized());
+    }
+
+    #[test]
+    fn vector3_should_implement_angle() {
+        let vec1 = Vector3::new(1., 0., 0.);
+        assert_eq!(PI/2., vec1.angle(&Vector3::new(0., 0., 1.)));
+        assert_eq!(PI, vec1.angle(&Vector3::new(-1., 0., 0.)));
+        assert_eq!(0., vec1.angle(&Vector3::new(0., 0., 0.)));
+
+        let vec1 = Vector3::new(0., 1e10, 0.);
+        assert_eq!(0., vec1.angle(&vec1));
+        assert_eq!(PI/2., Vector3::new(1e-12, 0., 0.).angle(&Vector3::new(0., 0., 1e-12)));
+    }
+
+    #[test]
+    fn vector3_angle_should_hold_at_large_magnitudes() {
+        let vec1 = Vector3::new(1e20, 0., 0.);
+        test_tools::assert_approx_eq!(PI/4., &vec1.angle(&Vector3::new(1e20, 1e20, 0.)));
+        assert_eq!(PI/2., vec1.angle(&Vector3::new(0., -1e-30, 0.)));
+        assert_eq!(PI, Vector3::new(f32::MAX, 0., 0.).angle(&Vector3::new(-f32::MAX, 0., 0.)));
+    }
+
+    #[test]
+    fn vector4_should_implement_dot_product() {
+        let vec1 = Vector4::new(1., 2., 3., 4.);
+        let vec2 = Vector4::new(4., -5., 6., -1.);
+        assert_eq!(8., vec1.dot(&vec2));
+        assert_eq!(vec1.dot(&vec2), vec2.dot(&vec1));
+    }
+
+    #[test]
+    fn vector4_should_implement_normalized() {
+        let vec1 = Vector4::new(1., 1., 1., 1.).normalized();
+        assert_eq!(Vector4::new(0.5, 0.5, 0.5, 0.5), vec1);
+        assert_eq!(Vector4::new(0., 0., 0., 0.), Vector4::new(0., 0., 0., 0.).normalized());
+    }
+
+    #[test]
+    fn vector4_should_implement_angle() {
+        let vec1 = Vector4::new(0., 0., 0., 1.);
+        assert_eq!(PI/2., vec1.angle(&Vector4::new(0., 1., 0., 0.)));
+        assert_eq!(PI, vec1.angle(&Vector4::new(0., 0., 0., -3.)));
+
+        let vec1 = Vector4::new(1e10, 0., 0., 1e10);
+        test_tools::assert_approx_eq!(0., &vec1.angle(&vec1));
+        assert_eq!(PI/2., Vector4::new(1e-12, 0., 0., 0.).angle(&Vector4::new(0., 1e-12, 0., 0.)));
+    }
+
+    #[test]
+    fn vector4_angle_should_hold_at_large_magnitudes() {
+        let vec1 = Vector4::new(1e20, 0., 0., 0.);
+        test_tools::assert_approx_eq!(PI/4., &vec1.angle(&Vector4::new(1e20, 0., 0., 1e20)));
+        assert_eq!(PI/2., vec1.angle(&Vector4::new(0., 0., -1e-30, 0.)));
+        assert_eq!(PI, Vector4::new(f32::MAX, 0., 0., 0.).angle(&Vector4::new(-f32::MAX, 0., 0., 0.)));
+    }
+
+    #[test]
+    fn vector2_should_implement_projected_on() {
+        let vec1 = Vector2::new(3., 4.);
+        assert_eq!(Some(Vector2::new(3., 0.)), vec1.projected_on(&Vector2::new(10., 0.)));
+        assert_eq!(Some(Vector2::new(3.5, 3.5)), vec1.projected_on(&Vector2::new(-1., -1.)));
+        assert_eq!(Some(Vector2::new(0., 0.)), vec1.projected_on(&Vector2::new(-4., 3.)));
+        assert_eq!(None, vec1.projected_on(&Vector2::new(0., 0.)));
+    }
+
+    #[test]
+    fn vector2_should_implement_rejected_from() {
+        let vec1 = Vector2::new(3., 4.);
+        assert_eq!(Some(Vector2::new(0., 4.)), vec1.rejected_from(&Vector2::new(10., 0.)));
+        assert_eq!(Some(Vector2::new(-0.5, 0.5)), vec1.rejected_from(&Vector2::new(1., 1.)));
+        assert_eq!(None, vec1.rejected_from(&Vector2::new(0., 0.)));
+    }
+
+    #[test]
+    fn vector2_should_implement_axis_projections() {
+        let vec1 = Vector2::new(3., 4.);
+        let axis = UnitVector2::y_axis();
+        assert_eq!(Vector2::new(0., 4.), vec1.projected_on_axis(&axis));
+        assert_eq!(Vector2::new(3., 0.), vec1.rejected_from_axis(&axis));
+    }
+
+    #[test]
+    fn vector2_should_implement_reflected() {
+        let normal = UnitVector2::y_axis();
+        assert_eq!(Vector2::new(1., 1.), Vector2::new(1., -1.).reflected(&normal));
+        assert_eq!(Vector2::new(1., -1.), Vector2::new(1., 1.).reflected(&-normal));
+        assert_eq!(Vector2::new(3., 0.), Vector2::new(3., 0.).reflected(&normal));
+
+        let normal = Vector2::new(1., 1.).try_normalize().unwrap();
+        let vec1 = Vector2::new(-2., 0.).reflected(&normal);
+        test_tools::assert_approx_eq!(0., &vec1.x());
+        test_tools::assert_approx_eq!(2., &vec1.y());
+    }
+
+    #[test]
+    fn vector2_should_implement_refracted() {
+        let normal = UnitVector2::y_axis();
+        let vec1 = Vector2::new(1., -1.);
+        crate::assert_vec_approx_eq!(vec1, vec1.refracted(&normal, 1.).unwrap());
+
+        let incident = Vector2::new((PI/6.).sin(), -(PI/6.).cos()) * 2.;
+        let vec1 = incident.refracted(&normal, 0.5).unwrap();
+        test_tools::assert_approx_eq!(0.5, &vec1.x());
+        test_tools::assert_approx_eq!(-2. * (1. - 0.0625_f32).sqrt(), &vec1.y());
+        test_tools::assert_approx_eq!(2., &vec1.magnitude());
+
+        let grazing = Vector2::new(1., -0.1);
+        assert_eq!(None, grazing.refracted(&normal, 1.5));
+        assert_eq!(Some(Vector2::new(0., 0.)), Vector2::new(0., 0.).refracted(&normal, 1.5));
+    }
+}
@@ -0,0 +1,73 @@
+pub mod conversions {
+    use crate::vector2::Vector2;
+    use crate::vector3::Vector3;
+    use crate::vector4::Vector4;
+    use crate::scalar::Scalar;
+
+    impl<T: Scalar, S> Vector2<T, S> {
+        pub fn extend(&self, z: T) -> Vector3<T> {
+            Vector3::new(self.x(), self.y(), z)
+        }
+    }
+
+    impl<T: Scalar> Vector3<T> {
+        pub fn extend(&self, w: T) -> Vector4<T> {
+            Vector4::new(self.x(), self.y(), self.z(), w)
+        }
+
+        pub fn truncate(&self) -> Vector2<T> {
+            Vector2::new(self.x(), self.y())
+        }
+    }
+
+    impl<T: Scalar> Vector4<T> {
+        pub fn truncate(&self) -> Vector3<T> {
+            Vector3::new(self.x(), self.y(), self.z())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    //  ________________________________
+    //
+    //  TESTS OVER DIMENSION CONVERSIONS
+    //  ________________________________
+    //  Vector2 Implements :
+    //  - extend        (Self, T) -> Vector3
+    //  Vector3 Implements :
+    //  - extend        (Self, T) -> Vector4
+    //  - truncate      (Self) -> Vector2
+    //  Vector4 Implements :
+    //  - truncate      (Self) -> Vector3
+
+    use crate::vector2::Vector2;
+    use crate::vector3::Vector3;
+    use crate::vector4::Vector4;
+
+    #[test]
+    fn vector2_should_implement_extend() {
+        let vec1 = Vector2::new(1., 2.);
+        assert_eq!(Vector3::new(1., 2., 3.), vec1.extend(3.));
+    }
+
+    #[test]
+    fn vector3_should_implement_extend() {
+        let vec1 = Vector3::new(1., 2., 3.);
+        assert_eq!(Vector4::new(1., 2., 3., 4.), vec1.extend(4.));
+    }
+
+    #[test]
+    fn vector3_should_implement_truncate() {
+        let vec1 = Vector3::new(1., 2., 3.);
+        assert_eq!(Vector2::new(1., 2.), vec1.truncate());
+        assert_eq!(vec1, vec1.truncate().extend(3.));
+    }
+
+    #[test]
+    fn vector4_should_implement_truncate() {
+        let vec1 = Vector4::new(1., 2., 3., 4.);
+        assert_eq!(Vector3::new(1., 2., 3.), vec1.truncate());
+        assert_eq!(vec1, vec1.truncate().extend(4.));
+    }
+}
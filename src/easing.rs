@@ -0,0 +1,347 @@
+use crate::point2::Point2;
+use crate::scalar::Float;
+use crate::vector2::Vector2;
+
+/// CSS `cubic-bezier(x1, y1, x2, y2)` timing function, from (0, 0) to (1, 1).
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct CubicBezier<T = f32> {
+    x1: T,
+    y1: T,
+    x2: T,
+    y2: T,
+}
+
+impl<T: Float> CubicBezier<T> {
+    /// `x1` and `x2` must lie in [0, 1] for the curve to be a function of time.
+    pub fn new(x1: T, y1: T, x2: T, y2: T) -> Self {
+        debug_assert!(
+            x1 >= T::ZERO && x1 <= T::ONE && x2 >= T::ZERO && x2 <= T::ONE,
+            "CubicBezier control points x must lie in [0, 1]"
+        );
+        CubicBezier{x1, y1, x2, y2}
+    }
+
+    /// CSS `ease`.
+    pub fn css_ease() -> Self {
+        CubicBezier::new(T::from_f64(0.25), T::from_f64(0.1), T::from_f64(0.25), T::ONE)
+    }
+
+    /// CSS `ease-in`.
+    pub fn css_ease_in() -> Self {
+        CubicBezier::new(T::from_f64(0.42), T::ZERO, T::ONE, T::ONE)
+    }
+
+    /// CSS `ease-out`.
+    pub fn css_ease_out() -> Self {
+        CubicBezier::new(T::ZERO, T::ZERO, T::from_f64(0.58), T::ONE)
+    }
+
+    /// CSS `ease-in-out`.
+    pub fn css_ease_in_out() -> Self {
+        CubicBezier::new(T::from_f64(0.42), T::ZERO, T::from_f64(0.58), T::ONE)
+    }
+
+    /// Evaluates one coordinate of the curve at the parameter `s`, given both control values.
+    fn bezier(s: T, p1: T, p2: T) -> T {
+        let three = T::from_f64(3.);
+        let c = three * p1;
+        let b = three * (p2 - p1) - c;
+        let a = T::ONE - c - b;
+        ((a * s + b) * s + c) * s
+    }
+
+    fn bezier_slope(s: T, p1: T, p2: T) -> T {
+        let three = T::from_f64(3.);
+        let c = three * p1;
+        let b = three * (p2 - p1) - c;
+        let a = T::ONE - c - b;
+        (three * a * s + (b + b)) * s + c
+    }
+
+    /// Finds the curve parameter whose x is `t` : Newton first, bisection when it stalls.
+    fn solve_x(&self, t: T) -> T {
+        let epsilon = T::from_f64(1e-6);
+
+        let mut s = t;
+        for _ in 0..8 {
+            let error = CubicBezier::bezier(s, self.x1, self.x2) - t;
+            if error.abs() < epsilon { return s; }
+            let slope = CubicBezier::bezier_slope(s, self.x1, self.x2);
+            if slope.abs() < epsilon { break; }
+            s -= error / slope;
+        }
+
+        let (mut lower, mut upper) = (T::ZERO, T::ONE);
+        s = t;
+        for _ in 0..32 {
+            let x = CubicBezier::bezier(s, self.x1, self.x2);
+            if (x - t).abs() < epsilon { break; }
+            if x < t { lower = s; } else { upper = s; }
+            s = (lower + upper) / (T::ONE + T::ONE);
+        }
+        s
+    }
+
+    pub fn ease(&self, t: T) -> T {
+        CubicBezier::bezier(self.solve_x(t), self.y1, self.y2)
+    }
+}
+
+/// Standard easing curves, mapping a progress in [0, 1] to an eased progress.
+/// `Back` and `Elastic` overshoot outside of [0, 1] on purpose.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Easing<T = f32> {
+    Linear,
+    QuadIn,
+    QuadOut,
+    QuadInOut,
+    CubicIn,
+    CubicOut,
+    CubicInOut,
+    SineIn,
+    SineOut,
+    SineInOut,
+    ExpoIn,
+    ExpoOut,
+    ExpoInOut,
+    ElasticIn,
+    ElasticOut,
+    ElasticInOut,
+    BackIn,
+    BackOut,
+    BackInOut,
+    BounceIn,
+    BounceOut,
+    BounceInOut,
+    CubicBezier(CubicBezier<T>),
+}
+
+fn bounce_out<T: Float>(t: T) -> T {
+    let n1 = T::from_f64(7.5625);
+    let d1 = T::from_f64(2.75);
+
+    if t < T::ONE / d1 { n1 * t * t }
+    else if t < T::from_f64(2.) / d1 {
+        let t = t - T::from_f64(1.5) / d1;
+        n1 * t * t + T::from_f64(0.75)
+    }
+    else if t < T::from_f64(2.5) / d1 {
+        let t = t - T::from_f64(2.25) / d1;
+        n1 * t * t + T::from_f64(0.9375)
+    }
+    else {
+        let t = t - T::from_f64(2.625) / d1;
+        n1 * t * t + T::from_f64(0.984375)
+    }
+}
+
+impl<T: Float> Easing<T> {
+    /// `t` is clamped to [0, 1], every curve maps 0 to 0 and 1 to 1.
+    pub fn ease(&self, t: T) -> T {
+        let t = if t < T::ZERO { T::ZERO } else if t > T::ONE { T::ONE } else { t };
+        let (one, two, half) = (T::ONE, T::ONE + T::ONE, T::from_f64(0.5));
+        let pow2 = |n: T| two.powf(n);
+        let back_c1 = T::from_f64(1.70158);
+
+        match self {
+            Easing::Linear => t,
+
+            Easing::QuadIn => t * t,
+            Easing::QuadOut => one - (one - t) * (one - t),
+            Easing::QuadInOut => {
+                if t < half { two * t * t }
+                else { one - (two - two * t).powf(two) / two }
+            }
+
+            Easing::CubicIn => t * t * t,
+            Easing::CubicOut => one - (one - t).powf(T::from_f64(3.)),
+            Easing::CubicInOut => {
+                if t < half { T::from_f64(4.) * t * t * t }
+                else { one - (two - two * t).powf(T::from_f64(3.)) / two }
+            }
+
+            Easing::SineIn => one - (t * T::PI / two).cos(),
+            Easing::SineOut => (t * T::PI / two).sin(),
+            Easing::SineInOut => (one - (T::PI * t).cos()) / two,
+
+            Easing::ExpoIn => if t == T::ZERO { T::ZERO } else { pow2(T::from_f64(10.) * t - T::from_f64(10.)) },
+            Easing::ExpoOut => if t == one { one } else { one - pow2(T::from_f64(-10.) * t) },
+            Easing::ExpoInOut => {
+                if t == T::ZERO || t == one { t }
+                else if t < half { pow2(T::from_f64(20.) * t - T::from_f64(10.)) / two }
+                else { (two - pow2(T::from_f64(10.) - T::from_f64(20.) * t)) / two }
+            }
+
+            Easing::ElasticIn | Easing::ElasticOut | Easing::ElasticInOut if t == T::ZERO || t == one => t,
+            Easing::ElasticIn => {
+                let c4 = (T::PI + T::PI) / T::from_f64(3.);
+                -pow2(T::from_f64(10.) * t - T::from_f64(10.)) * ((T::from_f64(10.) * t - T::from_f64(10.75)) * c4).sin()
+            }
+            Easing::ElasticOut => {
+                let c4 = (T::PI + T::PI) / T::from_f64(3.);
+                pow2(T::from_f64(-10.) * t) * ((T::from_f64(10.) * t - T::from_f64(0.75)) * c4).sin() + one
+            }
+            Easing::ElasticInOut => {
+                let c5 = (T::PI + T::PI) / T::from_f64(4.5);
+                let sin = ((T::from_f64(20.) * t - T::from_f64(11.125)) * c5).sin();
+                if t < half { -(pow2(T::from_f64(20.) * t - T::from_f64(10.)) * sin) / two }
+                else { pow2(T::from_f64(10.) - T::from_f64(20.) * t) * sin / two + one }
+            }
+
+            Easing::BackIn => {
+                let c3 = back_c1 + one;
+                c3 * t * t * t - back_c1 * t * t
+            }
+            Easing::BackOut => {
+                let (c3, t) = (back_c1 + one, t - one);
+                one + c3 * t * t * t + back_c1 * t * t
+            }
+            Easing::BackInOut => {
+                let c2 = back_c1 * T::from_f64(1.525);
+                let t = two * t;
+                if t < one { t * t * ((c2 + one) * t - c2) / two }
+                else {
+                    let t = t - two;
+                    (t * t * ((c2 + one) * t + c2) + two) / two
+                }
+            }
+
+            Easing::BounceIn => one - bounce_out(one - t),
+            Easing::BounceOut => bounce_out(t),
+            Easing::BounceInOut => {
+                if t < half { (one - bounce_out(one - two * t)) / two }
+                else { (one + bounce_out(two * t - one)) / two }
+            }
+
+            Easing::CubicBezier(curve) => curve.ease(t),
+        }
+    }
+}
+
+impl<T: Float, S> Vector2<T, S> {
+    pub fn lerp_eased(&self, other: &Self, t: T, easing: Easing<T>) -> Self {
+        self.lerp(other, easing.ease(t))
+    }
+}
+
+impl<T: Float, S> Point2<T, S> {
+    pub fn lerp_eased(&self, other: &Self, t: T, easing: Easing<T>) -> Self {
+        self.lerp(other, easing.ease(t))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    //  __________________
+    //
+    //  TESTS OVER EASINGS
+    //  __________________
+    //  Easing Implements :
+    //  - ease          (Self, f32) -> f32, quad, cubic, sine, expo, elastic, back, bounce
+    //  CubicBezier Implements :
+    //  - new, css_ease, css_ease_in, css_ease_out, css_ease_in_out
+    //  - ease          (Self, f32) -> f32
+    //  Vector2 and Point2 Implement :
+    //  - lerp_eased    (Self, Self, f32, Easing) -> Self
+
+    use super::{CubicBezier, Easing};
+    use crate::vector2::Vector2;
+    use crate::point2::Point2;
+    use crate::test_tools;
+
+    fn every_easing() -> Vec<Easing> {
+        vec![
+            Easing::Linear,
+            Easing::QuadIn, Easing::QuadOut, Easing::QuadInOut,
+            Easing::CubicIn, Easing::CubicOut, Easing::CubicInOut,
+            Easing::SineIn, Easing::SineOut, Easing::SineInOut,
+            Easing::ExpoIn, Easing::ExpoOut, Easing::ExpoInOut,
+            Easing::ElasticIn, Easing::ElasticOut, Easing::ElasticInOut,
+            Easing::BackIn, Easing::BackOut, Easing::BackInOut,
+            Easing::BounceIn, Easing::BounceOut, Easing::BounceInOut,
+            Easing::CubicBezier(CubicBezier::css_ease()),
+            Easing::CubicBezier(CubicBezier::css_ease_in_out()),
+        ]
+    }
+
+    #[test]
+    fn every_easing_should_map_ends_to_ends() {
+        for easing in every_easing() {
+            test_tools::assert_approx_eq!(0., &easing.ease(0.));
+            test_tools::assert_approx_eq!(1., &easing.ease(1.));
+            test_tools::assert_approx_eq!(0., &easing.ease(-1.));
+            test_tools::assert_approx_eq!(1., &easing.ease(2.));
+        }
+    }
+
+    #[test]
+    fn in_out_easings_should_be_symmetric() {
+        let in_outs = [
+            Easing::QuadInOut, Easing::CubicInOut, Easing::SineInOut, Easing::ExpoInOut,
+            Easing::ElasticInOut, Easing::BackInOut, Easing::BounceInOut,
+            Easing::CubicBezier(CubicBezier::css_ease_in_out()),
+        ];
+        for easing in in_outs {
+            test_tools::assert_approx_eq!(0.5, &easing.ease(0.5));
+            test_tools::assert_approx_eq!(1., &(easing.ease(0.2) + easing.ease(0.8)));
+        }
+    }
+
+    #[test]
+    fn easing_should_follow_reference_values() {
+        assert_eq!(0.25, Easing::QuadIn.ease(0.5));
+        assert_eq!(0.75, Easing::QuadOut.ease(0.5));
+        assert_eq!(0.875, Easing::CubicOut.ease(0.5));
+        assert_eq!(0.03125, Easing::ExpoIn.ease(0.5));
+        test_tools::assert_approx_eq!(std::f32::consts::FRAC_1_SQRT_2, &Easing::SineOut.ease(0.5));
+        test_tools::assert_approx_eq!(0.765_625, &Easing::BounceOut.ease(0.5));
+        test_tools::assert_approx_eq!(-0.087_697_6, &Easing::BackIn.ease(0.5));
+        test_tools::assert_approx_eq!(-0.015_625, &Easing::ElasticIn.ease(0.5));
+    }
+
+    #[test]
+    fn in_and_out_easings_should_mirror() {
+        let pairs = [
+            (Easing::QuadIn, Easing::QuadOut),
+            (Easing::CubicIn, Easing::CubicOut),
+            (Easing::SineIn, Easing::SineOut),
+            (Easing::ExpoIn, Easing::ExpoOut),
+            (Easing::ElasticIn, Easing::ElasticOut),
+            (Easing::BackIn, Easing::BackOut),
+            (Easing::BounceIn, Easing::BounceOut),
+        ];
+        for (ease_in, ease_out) in pairs {
+            for t in [0.1, 0.3, 0.6, 0.9] {
+                test_tools::assert_approx_eq!(1. - ease_in.ease(1. - t), &ease_out.ease(t));
+            }
+        }
+    }
+
+    #[test]
+    fn cubic_bezier_should_implement_ease() {
+        let linear = CubicBezier::new(0., 0., 1., 1.);
+        for t in [0., 0.25, 0.5, 0.75, 1.] {
+            test_tools::assert_approx_eq!(t, &linear.ease(t));
+        }
+
+        // cubic-bezier(0.25, 0.1, 0.25, 1) at 0.5, as rendered by browsers.
+        test_tools::assert_approx_eq!(0.802_403, &CubicBezier::css_ease().ease(0.5), 1e-3);
+        assert!(CubicBezier::css_ease_in().ease(0.3) < 0.3);
+        assert!(CubicBezier::css_ease_out().ease(0.3) > 0.3);
+
+        let steep = CubicBezier::new(0., 1., 0., 1.);
+        assert!(steep.ease(0.01) > 0.5);
+    }
+
+    #[test]
+    fn vector2_should_implement_lerp_eased() {
+        let vec1 = Vector2::new(0., 0.);
+        let vec2 = Vector2::new(4., -8.);
+        assert_eq!(Vector2::new(1., -2.), vec1.lerp_eased(&vec2, 0.5, Easing::QuadIn));
+        assert_eq!(vec1.lerp(&vec2, 0.3), vec1.lerp_eased(&vec2, 0.3, Easing::Linear));
+        assert_eq!(vec2, vec1.lerp_eased(&vec2, 1., Easing::CubicInOut));
+
+        let point1 = Point2::new(0., 0.);
+        assert_eq!(Point2::new(3., 3.), point1.lerp_eased(&Point2::new(4., 4.), 0.5, Easing::QuadOut));
+    }
+}
@@ -0,0 +1,47 @@
+use std::error::Error;
+use std::fmt::{self, Display};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Vector2Error {
+    NaN,
+    Infinite,
+    DivisionByZero,
+    /// The result does not fit the scalar, as `i32::MIN / -1`.
+    Overflow,
+}
+
+impl Display for Vector2Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Vector2Error::NaN => write!(f, "vector component is NaN"),
+            Vector2Error::Infinite => write!(f, "vector component is infinite"),
+            Vector2Error::DivisionByZero => write!(f, "vector division by zero"),
+            Vector2Error::Overflow => write!(f, "vector arithmetic overflowed"),
+        }
+    }
+}
+
+impl Error for Vector2Error {}
+
+/// Why a `Vector2` could not be read from text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseVector2Error {
+    /// An opening `(` or `[` without its closing counterpart, or the other way around.
+    UnbalancedDelimiter,
+    /// The text did not hold exactly two components, holds the count found, empty ones included.
+    ComponentCount(usize),
+    /// A component is not a valid scalar.
+    InvalidComponent { index: usize, text: String },
+}
+
+impl Display for ParseVector2Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseVector2Error::UnbalancedDelimiter => write!(f, "unbalanced vector delimiter"),
+            ParseVector2Error::ComponentCount(count) => write!(f, "expected 2 vector components, found {}", count),
+            ParseVector2Error::InvalidComponent { index, text } => write!(f, "invalid vector component {} : \"{}\"", index, text),
+        }
+    }
+}
+
+impl Error for ParseVector2Error {}
@@ -0,0 +1,471 @@
+use crate::scalar::{Real, Scalar, TotalOrder};
+use crate::vector2::Vector2;
+use std::cmp::Ordering;
+use std::fmt::{self, Debug};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign};
+
+const FRAC_BITS: u32 = 16;
+const RAW_ONE: i32 = 1 << FRAC_BITS;
+
+//  CORDIC tables and angles are kept in Q32 (i64) to absorb rounding,
+//  results are rounded back to Q16.16 once.
+const PI_Q32: i64 = 13_493_037_705;
+const HALF_PI_Q32: i64 = PI_Q32 / 2;
+const TWO_PI_Q32: i64 = PI_Q32 * 2;
+const CORDIC_GAIN_INV_Q32: i64 = 2_608_131_496;
+const CORDIC_ATAN_Q32: [i64; 32] = [
+    3_373_259_426, 1_991_351_318, 1_052_175_346, 534_100_635,
+    268_086_748, 134_174_063, 67_103_403, 33_553_749,
+    16_777_131, 8_388_597, 4_194_303, 2_097_152,
+    1_048_576, 524_288, 262_144, 131_072,
+    65_536, 32_768, 16_384, 8_192,
+    4_096, 2_048, 1_024, 512,
+    256, 128, 64, 32,
+    16, 8, 4, 2,
+];
+
+fn round_q32(value: i64) -> i32 {
+    ((value + (1 << (FRAC_BITS - 1))) >> FRAC_BITS) as i32
+}
+
+/// Q16.16 fixed-point number.
+/// Every operation, square roots and trigonometry included, is done on integers
+/// and yields the same bits on every machine. Overflow behaves like `i32` arithmetic.
+#[derive(Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Fixed(i32);
+
+impl Fixed {
+    pub const fn from_bits(bits: i32) -> Fixed {
+        Fixed(bits)
+    }
+
+    pub const fn to_bits(self) -> i32 {
+        self.0
+    }
+
+    pub const fn from_int(value: i16) -> Fixed {
+        Fixed((value as i32) << FRAC_BITS)
+    }
+
+    /// Returns `(sin, cos)` from a single CORDIC pass.
+    pub fn sin_cos(self) -> (Fixed, Fixed) {
+        let mut z = ((self.0 as i64) << FRAC_BITS).rem_euclid(TWO_PI_Q32);
+        if z > PI_Q32 { z -= TWO_PI_Q32; }
+
+        let flipped = z.abs() > HALF_PI_Q32;
+        if z > HALF_PI_Q32 { z -= PI_Q32; }
+        else if z < -HALF_PI_Q32 { z += PI_Q32; }
+
+        let (mut x, mut y) = (CORDIC_GAIN_INV_Q32, 0_i64);
+        for (i, atan) in CORDIC_ATAN_Q32.iter().enumerate() {
+            let (dx, dy) = (y >> i, x >> i);
+            if z >= 0 { x -= dx; y += dy; z -= atan; }
+            else { x += dx; y -= dy; z += atan; }
+        }
+
+        if flipped { (Fixed(-round_q32(y)), Fixed(-round_q32(x))) }
+        else { (Fixed(round_q32(y)), Fixed(round_q32(x))) }
+    }
+}
+
+impl Debug for Fixed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Fixed({})", self.to_f64())
+    }
+}
+
+impl Add for Fixed {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Fixed(self.0 + other.0)
+    }
+}
+
+impl AddAssign for Fixed {
+    fn add_assign(&mut self, other: Self) {
+        self.0 += other.0;
+    }
+}
+
+impl Sub for Fixed {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Fixed(self.0 - other.0)
+    }
+}
+
+impl SubAssign for Fixed {
+    fn sub_assign(&mut self, other: Self) {
+        self.0 -= other.0;
+    }
+}
+
+impl Mul for Fixed {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        let product = (self.0 as i64 * other.0 as i64) >> FRAC_BITS;
+        debug_assert!(i32::try_from(product).is_ok(), "Fixed multiplication overflowed");
+        Fixed(product as i32)
+    }
+}
+
+impl MulAssign for Fixed {
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+impl Div for Fixed {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        let quotient = ((self.0 as i64) << FRAC_BITS) / other.0 as i64;
+        debug_assert!(i32::try_from(quotient).is_ok(), "Fixed division overflowed");
+        Fixed(quotient as i32)
+    }
+}
+
+impl DivAssign for Fixed {
+    fn div_assign(&mut self, other: Self) {
+        *self = *self / other;
+    }
+}
+
+impl Rem for Fixed {
+    type Output = Self;
+
+    fn rem(self, other: Self) -> Self {
+        Fixed(self.0 % other.0)
+    }
+}
+
+impl RemAssign for Fixed {
+    fn rem_assign(&mut self, other: Self) {
+        self.0 %= other.0;
+    }
+}
+
+impl Neg for Fixed {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Fixed(-self.0)
+    }
+}
+
+/// Narrows a Q32 product back to Q16.16, overflowing like `Mul`.
+fn narrow_q32(value: i128) -> Fixed {
+    let narrowed = value >> FRAC_BITS;
+    debug_assert!(i32::try_from(narrowed).is_ok(), "Fixed multiplication overflowed");
+    Fixed(narrowed as i32)
+}
+
+//  The 2D products are summed in Q32 before narrowing, so that intermediate products
+//  beyond the Q16.16 range do not overflow when the result fits.
+impl Scalar for Fixed {
+    const ZERO: Self = Fixed(0);
+    const ONE: Self = Fixed(RAW_ONE);
+
+    fn checked_quotient(self, other: Self) -> Option<Self> {
+        if other.0 == 0 { return None; }
+        i32::try_from(((self.0 as i64) << FRAC_BITS) / other.0 as i64).ok().map(Fixed)
+    }
+
+    fn checked_remainder(self, other: Self) -> Option<Self> {
+        self.0.checked_rem(other.0).map(Fixed)
+    }
+
+    fn dot2(x1: Self, y1: Self, x2: Self, y2: Self) -> Self {
+        narrow_q32(x1.0 as i128 * x2.0 as i128 + y1.0 as i128 * y2.0 as i128)
+    }
+
+    fn det2(x1: Self, y1: Self, x2: Self, y2: Self) -> Self {
+        narrow_q32(x1.0 as i128 * y2.0 as i128 - y1.0 as i128 * x2.0 as i128)
+    }
+}
+
+impl Real for Fixed {
+    const PI: Self = Fixed(205_887);
+
+    /// Negative values have no root and yield zero.
+    fn sqrt(self) -> Self {
+        if self.0 <= 0 { return Fixed::ZERO; }
+        Fixed(((self.0 as u64) << FRAC_BITS).isqrt() as i32)
+    }
+
+    fn sin(self) -> Self {
+        self.sin_cos().0
+    }
+
+    fn cos(self) -> Self {
+        self.sin_cos().1
+    }
+
+    /// Input is clamped to [-1, 1].
+    fn acos(self) -> Self {
+        let x = self.0.clamp(-RAW_ONE, RAW_ONE) as i64;
+        let y = ((RAW_ONE as i64).pow(2) - x * x) as u64;
+        Fixed(y.isqrt() as i32).atan2(Fixed(x as i32))
+    }
+
+    fn atan2(self, other: Self) -> Self {
+        let (x, y) = (other.0 as i64, self.0 as i64);
+        if x == 0 && y == 0 { return Fixed::ZERO; }
+
+        //  Rotate into the right half-plane, CORDIC only converges there.
+        let (mut x, mut y, mut angle) =
+            if x >= 0 { (x, y, 0) }
+            else if y >= 0 { (y, -x, HALF_PI_Q32) }
+            else { (-y, x, -HALF_PI_Q32) };
+        x <<= 24;
+        y <<= 24;
+
+        for (i, atan) in CORDIC_ATAN_Q32.iter().enumerate() {
+            let (dx, dy) = (y >> i, x >> i);
+            if y > 0 { x += dx; y -= dy; angle += atan; }
+            else { x -= dx; y += dy; angle -= atan; }
+        }
+
+        Fixed(round_q32(angle))
+    }
+
+    fn abs(self) -> Self {
+        Fixed(self.0.abs())
+    }
+
+    fn fract(self) -> Self {
+        self - self.trunc()
+    }
+
+    fn floor(self) -> Self {
+        Fixed(self.0 & !(RAW_ONE - 1))
+    }
+
+    fn ceil(self) -> Self {
+        Fixed(self.0 + (RAW_ONE - 1)).floor()
+    }
+
+    /// Half-way cases round away from zero, like `f32::round`.
+    fn round(self) -> Self {
+        let half = Fixed(RAW_ONE / 2);
+        if self.0 >= 0 { (self + half).floor() }
+        else { -(half - self).floor() }
+    }
+
+    fn trunc(self) -> Self {
+        if self.0 >= 0 { self.floor() }
+        else { -(-self).floor() }
+    }
+
+    fn rem_euclid(self, n: Self) -> Self {
+        Fixed(self.0.rem_euclid(n.0))
+    }
+
+    /// Saturates out of range values, NaN becomes zero.
+    fn from_f64(value: f64) -> Self {
+        Fixed((value * RAW_ONE as f64).round() as i32)
+    }
+
+    fn to_f64(self) -> f64 {
+        self.0 as f64 / RAW_ONE as f64
+    }
+
+    /// The Q32 sum of squares is rooted directly, only the length has to fit.
+    fn length2(x: Self, y: Self) -> Self {
+        let squares = (x.0 as i64).pow(2) as u64 + (y.0 as i64).pow(2) as u64;
+        let length = squares.isqrt();
+        debug_assert!(i32::try_from(length).is_ok(), "Fixed length overflowed");
+        Fixed(length as i32)
+    }
+
+    /// `atan2` only needs the ratio of the Q32 determinent and dot product,
+    /// both are shifted down together until they fit.
+    fn angle2(x1: Self, y1: Self, x2: Self, y2: Self) -> Self {
+        let (x1, y1, x2, y2) = (x1.0 as i128, y1.0 as i128, x2.0 as i128, y2.0 as i128);
+        let (det, dot) = (x1 * y2 - y1 * x2, x1 * x2 + y1 * y2);
+        let bits = 128 - det.unsigned_abs().max(dot.unsigned_abs()).leading_zeros();
+        let shift = bits.saturating_sub(31);
+        Fixed((det >> shift) as i32).atan2(Fixed((dot >> shift) as i32))
+    }
+}
+
+impl TotalOrder for Fixed {
+    fn canonical(self) -> Self {
+        self
+    }
+
+    fn total_cmp(&self, other: &Self) -> Ordering {
+        self.cmp(other)
+    }
+
+    fn canonical_bits(self) -> u64 {
+        self.0 as u64
+    }
+}
+
+pub mod conversions {
+    use super::*;
+
+    impl<T: Real, S> Vector2<T, S> {
+        /// Converts between real representations, e.g. `Vector2` to `FixedVector2`.
+        pub fn cast<R: Real>(&self) -> Vector2<R, S> {
+            Vector2::in_space(R::from_f64(self.x().to_f64()), R::from_f64(self.y().to_f64()))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    //  ______________________________
+    //
+    //  TESTS OVER FIXED-POINT SCALARS
+    //  ______________________________
+    //  Fixed Implements :
+    //  - Scalar operators  (Self, Self) -> Self
+    //  - Real              sqrt, sin, cos, acos, atan2, rounding
+    //  FixedVector2 Implements :
+    //  - Every Vector2 operator and geometry method
+    //  - cast              (Vector2<T>) -> Vector2<R>
+
+    use super::Fixed;
+    use crate::scalar::{Real, Scalar};
+    use crate::vector2::{FixedVector2, Vector2};
+    use crate::test_tools;
+    use std::f32::consts::PI;
+
+    const FIXED_DELTA: f32 = 0.0002;
+
+    fn fixed(value: f64) -> Fixed {
+        Fixed::from_f64(value)
+    }
+
+    fn assert_fixed_approx(expected: f32, actual: Fixed) {
+        test_tools::assert_approx_eq!(expected, &(actual.to_f64() as f32), FIXED_DELTA);
+    }
+
+    #[test]
+    fn fixed_should_implement_arithmetic() {
+        assert_eq!(fixed(3.75), fixed(1.5) + fixed(2.25));
+        assert_eq!(fixed(-0.75), fixed(1.5) - fixed(2.25));
+        assert_eq!(fixed(3.375), fixed(1.5) * fixed(2.25));
+        assert_eq!(fixed(-0.5), fixed(1.) / fixed(-2.));
+        assert_eq!(fixed(0.75), fixed(2.25) % fixed(1.5));
+        assert_eq!(fixed(-1.5), -fixed(1.5));
+        assert_eq!(Fixed::ONE, Fixed::from_int(1));
+        assert_eq!(65536, Fixed::ONE.to_bits());
+    }
+
+    #[test]
+    fn fixed_should_implement_rounding() {
+        assert_eq!(fixed(1.), fixed(1.5).floor());
+        assert_eq!(fixed(-2.), fixed(-1.5).floor());
+        assert_eq!(fixed(2.), fixed(1.25).ceil());
+        assert_eq!(fixed(-1.), fixed(-1.25).ceil());
+        assert_eq!(fixed(2.), fixed(1.5).round());
+        assert_eq!(fixed(-2.), fixed(-1.5).round());
+        assert_eq!(fixed(-1.), fixed(-1.25).round());
+        assert_eq!(fixed(-1.), fixed(-1.75).trunc());
+        assert_eq!(fixed(-0.75), fixed(-1.75).fract());
+        assert_eq!(fixed(1.), fixed(-3.).rem_euclid(fixed(2.)));
+    }
+
+    #[test]
+    fn fixed_should_implement_sqrt() {
+        assert_eq!(fixed(2.), fixed(4.).sqrt());
+        assert_eq!(fixed(0.5), fixed(0.25).sqrt());
+        assert_eq!(Fixed::ZERO, fixed(-4.).sqrt());
+        assert_fixed_approx(2_f32.sqrt(), fixed(2.).sqrt());
+        assert_fixed_approx(181.0193, fixed(32768.).sqrt());
+    }
+
+    #[test]
+    fn fixed_should_implement_atan2() {
+        assert_eq!(Fixed::ZERO, Fixed::ZERO.atan2(Fixed::ZERO));
+        assert_fixed_approx(0., Fixed::ZERO.atan2(Fixed::ONE));
+        assert_fixed_approx(PI/2., Fixed::ONE.atan2(Fixed::ZERO));
+        assert_fixed_approx(PI, Fixed::ZERO.atan2(-Fixed::ONE));
+        assert_fixed_approx(-PI/2., (-Fixed::ONE).atan2(Fixed::ZERO));
+        assert_fixed_approx(-3.*PI/4., fixed(-2.).atan2(fixed(-2.)));
+        assert_fixed_approx(0.4636476, fixed(1.).atan2(fixed(2.)));
+        assert_fixed_approx(3_f32.atan2(-1000.), fixed(3.).atan2(fixed(-1000.)));
+    }
+
+    #[test]
+    fn fixed_should_implement_trigonometry() {
+        for step in -16..=16 {
+            let angle = step as f32 * PI / 5.;
+            let (sin, cos) = fixed(angle as f64).sin_cos();
+            assert_fixed_approx(angle.sin(), sin);
+            assert_fixed_approx(angle.cos(), cos);
+        }
+
+        assert_fixed_approx(PI/2., Fixed::ZERO.acos());
+        assert_fixed_approx(PI, (-Fixed::ONE).acos());
+        assert_fixed_approx(0., fixed(1.5).acos());
+        assert_fixed_approx(0.3_f32.acos(), fixed(0.3).acos());
+    }
+
+    #[test]
+    fn fixed_vector2_should_implement_geometry() {
+        let vec1 = FixedVector2::new(fixed(3.), fixed(4.));
+        assert_eq!(fixed(5.), vec1.magnitude());
+        assert_fixed_approx(0.6, vec1.normalized().x());
+        assert_fixed_approx(0.8, vec1.normalized().y());
+        assert_eq!(fixed(25.), vec1.dot(&vec1));
+
+        let vec1 = FixedVector2::new(Fixed::ONE, Fixed::ZERO);
+        let vec2 = FixedVector2::new(Fixed::ZERO, -Fixed::ONE);
+        assert_fixed_approx(PI/2., vec1.angle(&vec2));
+        assert_fixed_approx(-PI/2., vec1.signed_angle(&vec2));
+        assert_fixed_approx(3.*PI/2., vec1.wide_angle(&vec2));
+        assert_fixed_approx(-PI/2., vec2.to_angle());
+        assert_eq!(FixedVector2::new(fixed(1.), fixed(-1.)), vec1 + vec2);
+    }
+
+    #[test]
+    fn fixed_vector2_should_handle_game_coordinates() {
+        let vec1 = FixedVector2::new(Fixed::from_int(200), Fixed::ZERO);
+        assert_eq!(Fixed::from_int(200), vec1.magnitude());
+        assert_eq!(Fixed::ONE, vec1.normalized().x());
+
+        let vec1 = FixedVector2::new(Fixed::from_int(3000), Fixed::from_int(4000));
+        assert_eq!(Fixed::from_int(5000), vec1.magnitude());
+        assert_eq!(Fixed::ZERO, vec1.dot(&FixedVector2::new(Fixed::from_int(-400), Fixed::from_int(300))));
+        assert_eq!(Fixed::from_int(2500), vec1.dot(&FixedVector2::new(fixed(0.5), fixed(0.25))));
+
+        let vec1 = FixedVector2::new(Fixed::from_int(20), Fixed::ZERO);
+        let vec2 = FixedVector2::new(Fixed::ZERO, Fixed::from_int(20));
+        assert_fixed_approx(PI/2., vec1.angle(&vec2));
+
+        let vec1 = FixedVector2::new(Fixed::from_int(1000), Fixed::from_int(-1000));
+        let vec2 = FixedVector2::new(Fixed::from_int(-3000), Fixed::from_int(-3000));
+        assert_fixed_approx(PI/2., vec1.angle(&vec2));
+        assert_fixed_approx(-PI/2., vec1.signed_angle(&vec2));
+        assert_fixed_approx(3.*PI/2., vec1.wide_angle(&vec2));
+        assert_fixed_approx(0., vec1.angle(&vec1));
+        assert_fixed_approx(PI, vec1.angle(&FixedVector2::new(Fixed::from_int(-1000), Fixed::from_int(1000))));
+    }
+
+    #[test]
+    fn fixed_vector2_should_be_deterministic() {
+        let vec1 = FixedVector2::new(fixed(12.345), fixed(-6.789));
+        let vec2 = FixedVector2::new(fixed(-0.5), fixed(3.25));
+        assert_eq!(923_312, vec1.magnitude().to_bits());
+        assert_eq!(145_899, vec1.signed_angle(&vec2).to_bits());
+    }
+
+    #[test]
+    fn vector2_should_cast_to_fixed_and_back() {
+        let vec1 = Vector2::new(1.5, -2.25);
+        let fixed_vec: FixedVector2 = vec1.cast();
+        assert_eq!(FixedVector2::new(fixed(1.5), fixed(-2.25)), fixed_vec);
+        assert_eq!(vec1, fixed_vec.cast());
+
+        let vec1: Vector2<f64> = Vector2::new(0.1, 1e12).cast::<Fixed>().cast();
+        test_tools::assert_approx_eq!(0.1, &(vec1.x() as f32), 1. / 65536.);
+        assert_eq!(i32::MAX as f64 / 65536., vec1.y());
+    }
+}
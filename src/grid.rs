@@ -0,0 +1,249 @@
+pub mod checked_ops {
+    use crate::vector2::Vector2;
+    use crate::scalar::Integer;
+
+    impl<T: Integer, S> Vector2<T, S> {
+        pub fn checked_add(&self, other: &Self) -> Option<Self> {
+            Some(Vector2::in_space(
+                self.x().checked_add(other.x())?,
+                self.y().checked_add(other.y())?
+            ))
+        }
+
+        pub fn checked_sub(&self, other: &Self) -> Option<Self> {
+            Some(Vector2::in_space(
+                self.x().checked_sub(other.x())?,
+                self.y().checked_sub(other.y())?
+            ))
+        }
+
+        pub fn checked_mul(&self, other: &Self) -> Option<Self> {
+            Some(Vector2::in_space(
+                self.x().checked_mul(other.x())?,
+                self.y().checked_mul(other.y())?
+            ))
+        }
+
+        /// `None` on a zero component as well as on overflow.
+        pub fn checked_div(&self, other: &Self) -> Option<Self> {
+            Some(Vector2::in_space(
+                self.x().checked_div(other.x())?,
+                self.y().checked_div(other.y())?
+            ))
+        }
+
+        pub fn checked_rem(&self, other: &Self) -> Option<Self> {
+            Some(Vector2::in_space(
+                self.x().checked_rem(other.x())?,
+                self.y().checked_rem(other.y())?
+            ))
+        }
+
+        pub fn wrapping_add(&self, other: &Self) -> Self {
+            Vector2::in_space(self.x().wrapping_add(other.x()), self.y().wrapping_add(other.y()))
+        }
+
+        pub fn wrapping_sub(&self, other: &Self) -> Self {
+            Vector2::in_space(self.x().wrapping_sub(other.x()), self.y().wrapping_sub(other.y()))
+        }
+
+        pub fn wrapping_mul(&self, other: &Self) -> Self {
+            Vector2::in_space(self.x().wrapping_mul(other.x()), self.y().wrapping_mul(other.y()))
+        }
+
+        pub fn saturating_add(&self, other: &Self) -> Self {
+            Vector2::in_space(self.x().saturating_add(other.x()), self.y().saturating_add(other.y()))
+        }
+
+        pub fn saturating_sub(&self, other: &Self) -> Self {
+            Vector2::in_space(self.x().saturating_sub(other.x()), self.y().saturating_sub(other.y()))
+        }
+
+        pub fn saturating_mul(&self, other: &Self) -> Self {
+            Vector2::in_space(self.x().saturating_mul(other.x()), self.y().saturating_mul(other.y()))
+        }
+    }
+}
+
+pub mod grid_distance {
+    use crate::vector2::Vector2;
+    use crate::scalar::Integer;
+
+    //  Distances are unsigned, so that opposite ends of a signed range stay representable.
+    impl<T: Integer, S> Vector2<T, S> {
+        /// Saturates to `T::Unsigned::MAX` when the sum overflows.
+        pub fn manhattan_distance(&self, other: &Self) -> T::Unsigned {
+            self.x().abs_diff(other.x()).saturating_add(self.y().abs_diff(other.y()))
+        }
+
+        /// `None` when the sum overflows.
+        pub fn checked_manhattan_distance(&self, other: &Self) -> Option<T::Unsigned> {
+            self.x().abs_diff(other.x()).checked_add(self.y().abs_diff(other.y()))
+        }
+
+        pub fn chebyshev_distance(&self, other: &Self) -> T::Unsigned {
+            self.x().abs_diff(other.x()).max(self.y().abs_diff(other.y()))
+        }
+    }
+}
+
+pub mod grid_conversions {
+    use crate::vector2::Vector2;
+    use crate::scalar::{Integer, Real};
+
+    /// Out of range components saturate to the integer bounds, NaN becomes zero.
+    impl<T: Integer, S> Vector2<T, S> {
+        pub fn from_floor<R: Real>(vector: &Vector2<R, S>) -> Self {
+            Vector2::in_space(T::from_f64(vector.x().floor().to_f64()), T::from_f64(vector.y().floor().to_f64()))
+        }
+
+        pub fn from_ceil<R: Real>(vector: &Vector2<R, S>) -> Self {
+            Vector2::in_space(T::from_f64(vector.x().ceil().to_f64()), T::from_f64(vector.y().ceil().to_f64()))
+        }
+
+        pub fn from_round<R: Real>(vector: &Vector2<R, S>) -> Self {
+            Vector2::in_space(T::from_f64(vector.x().round().to_f64()), T::from_f64(vector.y().round().to_f64()))
+        }
+
+        pub fn from_trunc<R: Real>(vector: &Vector2<R, S>) -> Self {
+            Vector2::in_space(T::from_f64(vector.x().trunc().to_f64()), T::from_f64(vector.y().trunc().to_f64()))
+        }
+
+        pub fn to_real<R: Real>(&self) -> Vector2<R, S> {
+            Vector2::in_space(R::from_f64(self.x().to_f64()), R::from_f64(self.y().to_f64()))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    //  _____________________
+    //
+    //  TESTS OVER GRID TOOLS
+    //  _____________________
+    //  IVec2 and UVec2 Implement :
+    //  - self_ops and k_ops operators
+    //  - checked_*     (Self, Self) -> Option<Self>
+    //  - wrapping_*    (Self, Self) -> Self
+    //  - saturating_*  (Self, Self) -> Self
+    //  - manhattan_distance    (Self, Self) -> T::Unsigned, saturating
+    //  - checked_manhattan_distance    (Self, Self) -> Option<T::Unsigned>
+    //  - chebyshev_distance    (Self, Self) -> T::Unsigned
+    //  - from_floor, from_ceil, from_round, from_trunc     (Vector2) -> Self
+    //  - to_real       (Self) -> Vector2
+
+    use crate::vector2::{IVec2, UVec2, Vector2};
+
+    #[test]
+    fn ivec2_should_implement_operators() {
+        let vec1 = IVec2::new(7, -4);
+        let vec2 = IVec2::new(2, 3);
+        assert_eq!(IVec2::new(9, -1), vec1 + vec2);
+        assert_eq!(IVec2::new(5, -7), vec1 - vec2);
+        assert_eq!(IVec2::new(14, -12), vec1 * vec2);
+        assert_eq!(IVec2::new(3, -1), vec1 / vec2);
+        assert_eq!(IVec2::new(1, -1), vec1 % vec2);
+        assert_eq!(IVec2::new(14, -8), vec1 * 2);
+        assert_eq!(10, IVec2::new(1, 3).dot(&IVec2::new(1, 3)));
+    }
+
+    #[test]
+    fn ivec2_should_stay_exact_for_large_coordinates() {
+        let vec1 = IVec2::new(16_777_217, -16_777_217);
+        assert_eq!(IVec2::new(16_777_218, -16_777_216), vec1 + 1);
+    }
+
+    #[test]
+    fn ivec2_should_implement_checked_ops() {
+        let vec1 = IVec2::new(i32::MAX, 1);
+        assert_eq!(None, vec1.checked_add(&IVec2::new(1, 0)));
+        assert_eq!(Some(IVec2::new(i32::MAX, 2)), vec1.checked_add(&IVec2::new(0, 1)));
+        assert_eq!(None, IVec2::new(i32::MIN, 0).checked_sub(&IVec2::new(1, 0)));
+        assert_eq!(None, vec1.checked_mul(&IVec2::new(2, 1)));
+        assert_eq!(None, vec1.checked_div(&IVec2::new(1, 0)));
+        assert_eq!(Some(IVec2::new(i32::MAX, 1)), vec1.checked_div(&IVec2::new(1, 1)));
+        assert_eq!(None, vec1.checked_rem(&IVec2::new(0, 1)));
+        assert_eq!(Some(IVec2::new(1, 1)), IVec2::new(7, 5).checked_rem(&IVec2::new(3, 2)));
+    }
+
+    #[test]
+    fn ivec2_should_implement_wrapping_ops() {
+        let vec1 = IVec2::new(i32::MAX, i32::MIN);
+        assert_eq!(IVec2::new(i32::MIN, i32::MIN + 1), vec1.wrapping_add(&IVec2::new(1, 1)));
+        assert_eq!(IVec2::new(i32::MAX - 1, i32::MAX), vec1.wrapping_sub(&IVec2::new(1, 1)));
+        assert_eq!(IVec2::new(-2, 0), vec1.wrapping_mul(&IVec2::new(2, 2)));
+    }
+
+    #[test]
+    fn ivec2_should_implement_saturating_ops() {
+        let vec1 = IVec2::new(i32::MAX, i32::MIN);
+        assert_eq!(IVec2::new(i32::MAX, i32::MIN + 1), vec1.saturating_add(&IVec2::new(1, 1)));
+        assert_eq!(IVec2::new(i32::MAX - 1, i32::MIN), vec1.saturating_sub(&IVec2::new(1, 1)));
+        assert_eq!(IVec2::new(i32::MAX, i32::MIN), vec1.saturating_mul(&IVec2::new(2, 2)));
+    }
+
+    #[test]
+    fn uvec2_should_implement_saturating_ops() {
+        let vec1 = UVec2::new(1, u32::MAX);
+        assert_eq!(UVec2::new(0, u32::MAX - 2), vec1.saturating_sub(&UVec2::new(2, 2)));
+        assert_eq!(UVec2::new(3, u32::MAX), vec1.saturating_add(&UVec2::new(2, 2)));
+        assert_eq!(None, vec1.checked_sub(&UVec2::new(2, 0)));
+    }
+
+    #[test]
+    fn grid_vectors_should_implement_manhattan_distance() {
+        let vec1 = IVec2::new(-2, 3);
+        let vec2 = IVec2::new(4, -1);
+        assert_eq!(10, vec1.manhattan_distance(&vec2));
+        assert_eq!(10, vec2.manhattan_distance(&vec1));
+        assert_eq!(7, UVec2::new(1, 9).manhattan_distance(&UVec2::new(6, 7)));
+    }
+
+    #[test]
+    fn grid_vectors_should_not_overflow_distances() {
+        let vec1 = IVec2::new(i32::MIN, 0);
+        let vec2 = IVec2::new(i32::MAX, 0);
+        assert_eq!(u32::MAX, vec1.manhattan_distance(&vec2));
+        assert_eq!(Some(u32::MAX), vec1.checked_manhattan_distance(&vec2));
+        assert_eq!(u32::MAX, vec1.chebyshev_distance(&vec2));
+
+        let vec2 = IVec2::new(i32::MAX, 1);
+        assert_eq!(u32::MAX, vec1.manhattan_distance(&vec2));
+        assert_eq!(None, vec1.checked_manhattan_distance(&vec2));
+        assert_eq!(Some(7), IVec2::new(-2, 3).checked_manhattan_distance(&IVec2::new(1, -1)));
+    }
+
+    #[test]
+    fn grid_vectors_should_implement_chebyshev_distance() {
+        let vec1 = IVec2::new(-2, 3);
+        let vec2 = IVec2::new(4, -1);
+        assert_eq!(6, vec1.chebyshev_distance(&vec2));
+        assert_eq!(6, vec2.chebyshev_distance(&vec1));
+        assert_eq!(5, UVec2::new(1, 9).chebyshev_distance(&UVec2::new(6, 7)));
+    }
+
+    #[test]
+    fn ivec2_should_convert_from_vector2() {
+        let vec1 = Vector2::new(1.5, -1.5);
+        assert_eq!(IVec2::new(1, -2), IVec2::from_floor(&vec1));
+        assert_eq!(IVec2::new(2, -1), IVec2::from_ceil(&vec1));
+        assert_eq!(IVec2::new(2, -2), IVec2::from_round(&vec1));
+        assert_eq!(IVec2::new(1, -1), IVec2::from_trunc(&vec1));
+
+        let vec1 = Vector2::new(1e12_f64, -1.);
+        assert_eq!(IVec2::new(i32::MAX, -1), IVec2::from_floor(&vec1));
+        assert_eq!(UVec2::new(u32::MAX, 0), UVec2::from_floor(&vec1));
+    }
+
+    #[test]
+    fn ivec2_should_convert_to_vector2() {
+        let vec1 = IVec2::new(3, -4);
+        let vec2: Vector2 = vec1.to_real();
+        assert_eq!(Vector2::new(3., -4.), vec2);
+        assert_eq!(5., vec2.magnitude());
+
+        let vec1 = IVec2::new(16_777_217, 0);
+        let vec2: Vector2<f64> = vec1.to_real();
+        assert_eq!(vec1, IVec2::from_floor(&vec2));
+    }
+}
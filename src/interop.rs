@@ -0,0 +1,152 @@
+pub mod conversions {
+    use crate::vector2::Vector2;
+    use crate::scalar::Scalar;
+
+    impl<T: Scalar, S> From<[T; 2]> for Vector2<T, S> {
+        fn from(components: [T; 2]) -> Self {
+            Vector2::in_space(components[0], components[1])
+        }
+    }
+
+    impl<T: Scalar, S> From<(T, T)> for Vector2<T, S> {
+        fn from((x, y): (T, T)) -> Self {
+            Vector2::in_space(x, y)
+        }
+    }
+
+    impl<T: Scalar, S> From<Vector2<T, S>> for [T; 2] {
+        fn from(vector: Vector2<T, S>) -> Self {
+            [vector.x(), vector.y()]
+        }
+    }
+
+    impl<T: Scalar, S> From<Vector2<T, S>> for (T, T) {
+        fn from(vector: Vector2<T, S>) -> Self {
+            (vector.x(), vector.y())
+        }
+    }
+
+    impl<T: Scalar, S> IntoIterator for Vector2<T, S> {
+        type Item = T;
+        type IntoIter = std::array::IntoIter<T, 2>;
+
+        fn into_iter(self) -> Self::IntoIter {
+            <[T; 2]>::from(self).into_iter()
+        }
+    }
+}
+
+pub mod slices {
+    use crate::vector2::Vector2;
+    use crate::scalar::Scalar;
+
+    //  Vector2 is #[repr(C)] over two T, its space marker being zero-sized :
+    //  it shares the size and alignment of [T; 2], so [T] of even length and
+    //  [Vector2<T, S>] can be reinterpreted into each other.
+    //  Going through these skips the `validate` feature checks.
+    impl<T: Scalar, S> Vector2<T, S> {
+        /// Reads interleaved `x, y` components without copying, `None` on an odd length.
+        pub fn from_components(components: &[T]) -> Option<&[Self]> {
+            if !components.len().is_multiple_of(2) { return None; }
+            // SAFETY: same layout as [T; 2], length checked above.
+            Some(unsafe { std::slice::from_raw_parts(components.as_ptr() as *const Self, components.len() / 2) })
+        }
+
+        pub fn from_components_mut(components: &mut [T]) -> Option<&mut [Self]> {
+            if !components.len().is_multiple_of(2) { return None; }
+            // SAFETY: same layout as [T; 2], length checked above.
+            Some(unsafe { std::slice::from_raw_parts_mut(components.as_mut_ptr() as *mut Self, components.len() / 2) })
+        }
+
+        /// Views vectors as their interleaved `x, y` components without copying.
+        pub fn as_components(vectors: &[Self]) -> &[T] {
+            // SAFETY: same layout as [T; 2].
+            unsafe { std::slice::from_raw_parts(vectors.as_ptr() as *const T, vectors.len() * 2) }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    //  ________________________
+    //
+    //  TESTS OVER INTEROP TOOLS
+    //  ________________________
+    //  Vector2 Implements :
+    //  - From<[T; 2]>, From<(T, T)>, Into<[T; 2]>, Into<(T, T)>
+    //  - Index<usize>, IndexMut<usize>
+    //  - IntoIterator over its components
+    //  - from_components, from_components_mut  (&[T]) -> Option<&[Self]>
+    //  - as_components     (&[Self]) -> &[T]
+
+    use crate::vector2::Vector2;
+
+    #[test]
+    fn vector2_should_convert_from_array_and_tuple() {
+        assert_eq!(Vector2::new(1., 2.), Vector2::from([1., 2.]));
+        assert_eq!(Vector2::new(1., 2.), Vector2::from((1., 2.)));
+        let vec1: Vector2 = [3., 4.].into();
+        assert_eq!(5., vec1.magnitude());
+    }
+
+    #[test]
+    fn vector2_should_convert_into_array_and_tuple() {
+        let array: [f32; 2] = Vector2::new(1., 2.).into();
+        assert_eq!([1., 2.], array);
+        let (x, y): (f32, f32) = Vector2::new(1., 2.).into();
+        assert_eq!((1., 2.), (x, y));
+    }
+
+    #[test]
+    fn vector2_should_implement_index() {
+        let mut vec1 = Vector2::new(1., 2.);
+        assert_eq!(1., vec1[0]);
+        assert_eq!(2., vec1[1]);
+        vec1[1] = 5.;
+        vec1[0] += 1.;
+        assert_eq!(Vector2::new(2., 5.), vec1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn vector2_index_should_panic_out_of_range() {
+        let _ = Vector2::new(1., 2.)[2];
+    }
+
+    #[test]
+    fn vector2_should_implement_into_iterator() {
+        let vec1 = Vector2::new(3., -4.);
+        assert_eq!(vec![3., -4.], vec1.into_iter().collect::<Vec<f32>>());
+        assert_eq!(-1., vec1.into_iter().sum::<f32>());
+        let mut count = 0;
+        for _ in vec1 { count += 1; }
+        assert_eq!(2, count);
+    }
+
+    #[test]
+    fn vector2_should_implement_from_components() {
+        let buffer = [1., 2., 3., 4., 5., 6.];
+        let vectors = Vector2::<f32>::from_components(&buffer).unwrap();
+        assert_eq!(3, vectors.len());
+        assert_eq!(Vector2::new(3., 4.), vectors[1]);
+        assert_eq!(buffer.as_ptr() as usize, vectors.as_ptr() as usize);
+
+        assert_eq!(None, Vector2::<f32>::from_components(&buffer[..5]));
+        assert_eq!(Some(&[][..]), Vector2::<f32>::from_components(&[]));
+    }
+
+    #[test]
+    fn vector2_should_implement_from_components_mut() {
+        let mut buffer = [1., 2., 3., 4.];
+        for vector in Vector2::<f32>::from_components_mut(&mut buffer).unwrap() {
+            *vector *= 2.;
+        }
+        assert_eq!([2., 4., 6., 8.], buffer);
+    }
+
+    #[test]
+    fn vector2_should_implement_as_components() {
+        let vectors = [Vector2::new(1., 2.), Vector2::new(3., 4.)];
+        assert_eq!(&[1., 2., 3., 4.], Vector2::as_components(&vectors));
+    }
+}
@@ -0,0 +1,194 @@
+use crate::scalar::TotalOrder;
+use crate::vector2::Vector2;
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+
+/// Opt-in `Eq`, `Hash` and lexicographic `Ord` over a `Vector2`, following the IEEE total order.
+/// -0.0 and 0.0 are the same key, as are all NaN.
+pub struct OrderedVector2<T = f32, S = crate::space::UnknownSpace>(Vector2<T, S>);
+
+impl<T: TotalOrder, S> OrderedVector2<T, S> {
+    pub fn new(vector: Vector2<T, S>) -> OrderedVector2<T, S> {
+        OrderedVector2(Vector2::in_space(vector.x().canonical(), vector.y().canonical()))
+    }
+
+    /// The canonicalized vector.
+    pub fn vector(&self) -> Vector2<T, S> {
+        self.0
+    }
+}
+
+impl<T: TotalOrder, S> PartialEq for OrderedVector2<T, S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T: TotalOrder, S> Eq for OrderedVector2<T, S> {}
+
+impl<T: TotalOrder, S> PartialOrd for OrderedVector2<T, S> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: TotalOrder, S> Ord for OrderedVector2<T, S> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.x().total_cmp(&other.0.x()).then_with(|| self.0.y().total_cmp(&other.0.y()))
+    }
+}
+
+impl<T: TotalOrder, S> Hash for OrderedVector2<T, S> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.x().canonical_bits().hash(state);
+        self.0.y().canonical_bits().hash(state);
+    }
+}
+
+impl<T: TotalOrder, S> Copy for OrderedVector2<T, S> {}
+
+impl<T: TotalOrder, S> Clone for OrderedVector2<T, S> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: TotalOrder, S> std::fmt::Debug for OrderedVector2<T, S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("OrderedVector2").field(&self.0).finish()
+    }
+}
+
+/// Grid cell of side `tolerance` holding a vector, used to weld nearby points.
+/// Two points closer than `tolerance` either share a key or have neighbouring keys.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct QuantizedKey {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl QuantizedKey {
+    /// The key itself and its 8 surrounding cells.
+    pub fn neighbors(&self) -> [QuantizedKey; 9] {
+        let mut keys = [*self; 9];
+        for (i, key) in keys.iter_mut().enumerate() {
+            key.x += i as i64 % 3 - 1;
+            key.y += i as i64 / 3 - 1;
+        }
+        keys
+    }
+}
+
+pub mod key_tools {
+    use super::{OrderedVector2, QuantizedKey};
+    use crate::scalar::{Real, TotalOrder};
+    use crate::vector2::Vector2;
+
+    impl<T: TotalOrder, S> Vector2<T, S> {
+        pub fn ordered(&self) -> OrderedVector2<T, S> {
+            OrderedVector2::new(*self)
+        }
+    }
+
+    impl<T: Real, S> Vector2<T, S> {
+        /// Components are rounded to the nearest multiple of `tolerance`.
+        pub fn quantized_key(&self, tolerance: T) -> QuantizedKey {
+            QuantizedKey {
+                x: (self.x() / tolerance).round().to_f64() as i64,
+                y: (self.y() / tolerance).round().to_f64() as i64,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    //  _______________________
+    //
+    //  TESTS OVER VECTOR2 KEYS
+    //  _______________________
+    //  Vector2 Implements :
+    //  - Eq, Hash      for integer and fixed-point scalars
+    //  - ordered       (Self) -> OrderedVector2
+    //  - quantized_key (Self, T) -> QuantizedKey
+    //  OrderedVector2 Implements :
+    //  - Eq, Hash, Ord with -0.0 and NaN canonicalization
+    //  QuantizedKey Implements :
+    //  - neighbors     (Self) -> [Self; 9]
+
+    use super::QuantizedKey;
+    use crate::vector2::{IVec2, Vector2};
+    use std::collections::{BTreeSet, HashMap, HashSet};
+
+    #[test]
+    fn integer_vector2_should_be_hashable() {
+        let mut tiles = HashMap::new();
+        tiles.insert(IVec2::new(1, 2), "grass");
+        tiles.insert(IVec2::new(-1, 2), "water");
+        assert_eq!(Some(&"grass"), tiles.get(&IVec2::new(1, 2)));
+        assert_eq!(None, tiles.get(&IVec2::new(2, 1)));
+    }
+
+    #[test]
+    fn ordered_vector2_should_deduplicate() {
+        let mut vertices = HashSet::new();
+        vertices.insert(Vector2::new(1., 2.).ordered());
+        vertices.insert(Vector2::new(1., 2.).ordered());
+        vertices.insert(Vector2::new(2., 1.).ordered());
+        assert_eq!(2, vertices.len());
+    }
+
+    #[test]
+    #[cfg(not(feature = "validate"))]
+    fn ordered_vector2_should_canonicalize_zero_and_nan() {
+        assert_eq!(Vector2::new(0., 1.).ordered(), Vector2::new(-0., 1.).ordered());
+        assert_eq!(Vector2::new(f32::NAN, 1.).ordered(), Vector2::new(-f32::NAN, 1.).ordered());
+        assert_ne!(Vector2::new(f32::NAN, 1.).ordered(), Vector2::new(0., 1.).ordered());
+
+        let mut vertices = HashSet::new();
+        vertices.insert(Vector2::new(-0., f32::NAN).ordered());
+        vertices.insert(Vector2::new(0., -f32::NAN).ordered());
+        assert_eq!(1, vertices.len());
+        assert!(vertices.iter().next().unwrap().vector().x().is_sign_positive());
+
+        // Infinities sort at their ends, NaN after every other value.
+        assert!(Vector2::new(f32::NEG_INFINITY, 0.).ordered() < Vector2::new(-1., 5.).ordered());
+        assert!(Vector2::new(f32::NAN, 0.).ordered() > Vector2::new(f32::INFINITY, 0.).ordered());
+    }
+
+    #[test]
+    fn ordered_vector2_should_sort_lexicographically() {
+        let sorted: Vec<Vector2> = [
+            Vector2::new(1., 2.),
+            Vector2::new(-1., 5.),
+            Vector2::new(1., -2.),
+            Vector2::new(-3., 0.),
+        ].iter().map(|v| v.ordered()).collect::<BTreeSet<_>>().iter().map(|k| k.vector()).collect();
+
+        assert_eq!(Vector2::new(-3., 0.), sorted[0]);
+        assert_eq!(Vector2::new(-1., 5.), sorted[1]);
+        assert_eq!(Vector2::new(1., -2.), sorted[2]);
+        assert_eq!(Vector2::new(1., 2.), sorted[3]);
+    }
+
+    #[test]
+    fn vector2_should_implement_quantized_key() {
+        let key = Vector2::new(1.04, -2.96).quantized_key(0.1);
+        assert_eq!(QuantizedKey { x: 10, y: -30 }, key);
+        assert_eq!(key, Vector2::new(0.98, -3.01).quantized_key(0.1));
+        assert_ne!(key, Vector2::new(1.06, -3.01).quantized_key(0.1));
+    }
+
+    #[test]
+    fn quantized_key_should_implement_neighbors() {
+        let key1 = Vector2::new(1.049, 0.).quantized_key(0.1);
+        let key2 = Vector2::new(1.051, 0.).quantized_key(0.1);
+        assert_ne!(key1, key2);
+        assert!(key1.neighbors().contains(&key2));
+
+        let neighbors = QuantizedKey { x: 0, y: 0 }.neighbors();
+        assert_eq!(QuantizedKey { x: -1, y: -1 }, neighbors[0]);
+        assert_eq!(QuantizedKey { x: 0, y: 0 }, neighbors[4]);
+        assert_eq!(QuantizedKey { x: 1, y: 1 }, neighbors[8]);
+    }
+}
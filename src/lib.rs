@@ -1,45 +1,430 @@
+pub mod scalar;
+pub mod fixed;
+pub mod space;
+pub mod error;
+pub mod keys;
+pub mod text;
+pub mod approx;
+pub mod polar;
+pub mod angle;
+pub mod easing;
+pub mod segment;
+pub mod shapes;
+pub mod ray;
+
 pub mod vector2 {
-    fn magnitude_of(vector: &Vector2) -> f32 {
-        magnitude(vector.x, vector.y)
+    use crate::scalar::{Real, Scalar};
+    use crate::space::UnknownSpace;
+    use std::fmt::{self, Debug};
+    use std::hash::{Hash, Hasher};
+    use std::marker::PhantomData;
+    use std::ops::{Index, IndexMut};
+    
+    /// `S` tags the coordinate space, operators only combine vectors of the same space :
+    /// ```compile_fail
+    /// use vector2::vector2::Vector2;
+    /// use vector2::space::{ScreenSpace, WorldSpace};
+    /// let _ = Vector2::<f32, WorldSpace>::in_space(1., 2.) + Vector2::<f32, ScreenSpace>::in_space(3., 4.);
+    /// ```
+    /// The layout is that of `[T; 2]`, see `Vector2::from_components` for zero-copy slices.
+    #[repr(C)]
+    pub struct Vector2<T = f32, S = UnknownSpace> {
+        x: T,
+        y: T,
+        space: PhantomData<S>,
     }
 
-    fn magnitude(x: f32, y: f32) -> f32 {
-        (x.powf(2.) + y.powf(2.)).sqrt()
+    #[cfg(feature = "validate")]
+    fn validate<T: Scalar>(x: T, y: T) {
+        debug_assert!(x.is_finite() && y.is_finite(), "Vector2 built from non-finite components ({:?}, {:?})", x, y);
     }
-    
-    #[derive(Debug, Copy, Clone, PartialEq)]
-    pub struct Vector2 {
-        x: f32,
-        y: f32,
-        magnitude: f32,
+
+    #[cfg(not(feature = "validate"))]
+    fn validate<T: Scalar>(_x: T, _y: T) {}
+
+    /// Grid cell coordinates, see `grid` for the overflow aware operations.
+    pub type IVec2 = Vector2<i32>;
+    pub type UVec2 = Vector2<u32>;
+
+    /// Deterministic across machines, see `fixed::Fixed`.
+    pub type FixedVector2 = Vector2<crate::fixed::Fixed>;
+
+    impl<T: Scalar> Vector2<T> {
+        pub fn new(x: T, y: T) -> Vector2<T> {
+            Vector2::in_space(x, y)
+        }
     }
 
-    impl Vector2 {
-        pub fn new(x: f32, y: f32) -> Vector2 {
-            let magnitude = magnitude(x, y);
-            Vector2{x, y, magnitude}
+    impl<T: Scalar, S> Vector2<T, S> {
+        /// Builds a vector tagged with the space `S`.
+        pub fn in_space(x: T, y: T) -> Vector2<T, S> {
+            validate(x, y);
+            Vector2{x, y, space: PhantomData}
         }
 
-        pub fn x(&self) -> f32 {
+        pub fn x(&self) -> T {
             self.x
         }
 
-        pub fn y(&self) -> f32 {
+        pub fn y(&self) -> T {
             self.y
         }
         
-        pub fn magnitude(&self) -> f32 {
-            self.magnitude
+        pub fn magnitude_squared(&self) -> T {
+            T::dot2(self.x, self.y, self.x, self.y)
         }
         
-        pub fn set_x(&mut self, x: f32) {
+        pub fn set_x(&mut self, x: T) {
+            validate(x, self.y);
             self.x = x;
-            self.magnitude = magnitude_of(&self);
         }
         
-        pub fn set_y(&mut self, y: f32) {
+        pub fn set_y(&mut self, y: T) {
+            validate(self.x, y);
+            self.y = y;
+        }
+
+        /// A copy with `x` replaced, `set_x` without the mutable binding.
+        pub fn with_x(&self, x: T) -> Self {
+            Vector2::in_space(x, self.y)
+        }
+
+        /// A copy with `y` replaced, `set_y` without the mutable binding.
+        pub fn with_y(&self, y: T) -> Self {
+            Vector2::in_space(self.x, y)
+        }
+
+        /// Reinterprets the vector in another space, without any transformation.
+        pub fn cast_space<S2>(&self) -> Vector2<T, S2> {
+            Vector2::in_space(self.x, self.y)
+        }
+    }
+
+    impl<T: Real, S> Vector2<T, S> {
+        /// Computed on demand, prefer `magnitude_squared` when only comparing lengths.
+        pub fn magnitude(&self) -> T {
+            T::length2(self.x, self.y)
+        }
+    }
+
+    //  Implemented by hand, deriving would require the space marker to implement them too.
+
+    impl<T: Copy, S> Copy for Vector2<T, S> {}
+
+    impl<T: Clone, S> Clone for Vector2<T, S> {
+        fn clone(&self) -> Self {
+            Vector2{x: self.x.clone(), y: self.y.clone(), space: PhantomData}
+        }
+    }
+
+    impl<T: PartialEq, S> PartialEq for Vector2<T, S> {
+        fn eq(&self, other: &Self) -> bool {
+            self.x == other.x && self.y == other.y
+        }
+    }
+
+    impl<T: Eq, S> Eq for Vector2<T, S> {}
+
+    impl<T: Hash, S> Hash for Vector2<T, S> {
+        fn hash<H: Hasher>(&self, state: &mut H) {
+            self.x.hash(state);
+            self.y.hash(state);
+        }
+    }
+
+    impl<T, S> Index<usize> for Vector2<T, S> {
+        type Output = T;
+
+        fn index(&self, index: usize) -> &T {
+            match index {
+                0 => &self.x,
+                1 => &self.y,
+                _ => panic!("Vector2 index out of range : {}", index),
+            }
+        }
+    }
+
+    /// Writes through the index skip the `validate` feature checks.
+    impl<T, S> IndexMut<usize> for Vector2<T, S> {
+        fn index_mut(&mut self, index: usize) -> &mut T {
+            match index {
+                0 => &mut self.x,
+                1 => &mut self.y,
+                _ => panic!("Vector2 index out of range : {}", index),
+            }
+        }
+    }
+
+    impl<T: Debug, S> Debug for Vector2<T, S> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_struct("Vector2").field("x", &self.x).field("y", &self.y).finish()
+        }
+    }
+}
+
+pub mod point2 {
+    use crate::scalar::Scalar;
+    use crate::space::UnknownSpace;
+    use crate::vector2::Vector2;
+    use std::fmt::{self, Debug};
+    use std::marker::PhantomData;
+
+    /// A position, where `Vector2` is a displacement.
+    /// point - point = vector, point ± vector = point, and two points cannot be added :
+    /// ```compile_fail
+    /// use vector2::point2::Point2;
+    /// let _ = Point2::new(1., 2.) + Point2::new(3., 4.);
+    /// ```
+    pub struct Point2<T = f32, S = UnknownSpace> {
+        x: T,
+        y: T,
+        space: PhantomData<S>,
+    }
+
+    impl<T: Scalar> Point2<T> {
+        pub fn new(x: T, y: T) -> Point2<T> {
+            Point2::in_space(x, y)
+        }
+    }
+
+    impl<T: Scalar, S> Point2<T, S> {
+        /// Builds a point tagged with the space `S`.
+        pub fn in_space(x: T, y: T) -> Point2<T, S> {
+            Point2{x, y, space: PhantomData}
+        }
+
+        pub fn origin() -> Point2<T, S> {
+            Point2::in_space(T::ZERO, T::ZERO)
+        }
+
+        pub fn x(&self) -> T {
+            self.x
+        }
+
+        pub fn y(&self) -> T {
+            self.y
+        }
+
+        pub fn set_x(&mut self, x: T) {
+            self.x = x;
+        }
+
+        pub fn set_y(&mut self, y: T) {
+            self.y = y;
+        }
+
+        /// The point's displacement from the origin.
+        pub fn to_vector(&self) -> Vector2<T, S> {
+            Vector2::in_space(self.x, self.y)
+        }
+
+        /// The point at `vector` from the origin.
+        pub fn from_vector(vector: &Vector2<T, S>) -> Point2<T, S> {
+            Point2::in_space(vector.x(), vector.y())
+        }
+
+        /// Reinterprets the point in another space, without any transformation.
+        pub fn cast_space<S2>(&self) -> Point2<T, S2> {
+            Point2::in_space(self.x, self.y)
+        }
+    }
+
+    impl<T: Copy, S> Copy for Point2<T, S> {}
+
+    impl<T: Clone, S> Clone for Point2<T, S> {
+        fn clone(&self) -> Self {
+            Point2{x: self.x.clone(), y: self.y.clone(), space: PhantomData}
+        }
+    }
+
+    impl<T: PartialEq, S> PartialEq for Point2<T, S> {
+        fn eq(&self, other: &Self) -> bool {
+            self.x == other.x && self.y == other.y
+        }
+    }
+
+    impl<T: Debug, S> Debug for Point2<T, S> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_struct("Point2").field("x", &self.x).field("y", &self.y).finish()
+        }
+    }
+}
+
+pub mod unit_vector2 {
+    use crate::scalar::Scalar;
+    use crate::space::UnknownSpace;
+    use crate::vector2::Vector2;
+    use std::fmt::{self, Debug};
+
+    /// A `Vector2` of length 1, built with `Vector2::try_normalize`.
+    pub struct UnitVector2<T = f32, S = UnknownSpace>(Vector2<T, S>);
+
+    impl<T: Scalar, S> UnitVector2<T, S> {
+        /// The caller guarantees `vector` is of length 1.
+        pub fn new_unchecked(vector: Vector2<T, S>) -> UnitVector2<T, S> {
+            UnitVector2(vector)
+        }
+
+        pub fn x_axis() -> UnitVector2<T, S> {
+            UnitVector2(Vector2::in_space(T::ONE, T::ZERO))
+        }
+
+        pub fn y_axis() -> UnitVector2<T, S> {
+            UnitVector2(Vector2::in_space(T::ZERO, T::ONE))
+        }
+
+        pub fn x(&self) -> T {
+            self.0.x()
+        }
+
+        pub fn y(&self) -> T {
+            self.0.y()
+        }
+
+        /// Known to be 1, nothing is computed.
+        pub fn magnitude(&self) -> T {
+            T::ONE
+        }
+
+        pub fn as_vector(&self) -> &Vector2<T, S> {
+            &self.0
+        }
+
+        pub fn to_vector(&self) -> Vector2<T, S> {
+            self.0
+        }
+    }
+
+    impl<T: Copy, S> Copy for UnitVector2<T, S> {}
+
+    impl<T: Copy, S> Clone for UnitVector2<T, S> {
+        fn clone(&self) -> Self {
+            *self
+        }
+    }
+
+    impl<T: PartialEq, S> PartialEq for UnitVector2<T, S> {
+        fn eq(&self, other: &Self) -> bool {
+            self.0 == other.0
+        }
+    }
+
+    impl<T: Debug, S> Debug for UnitVector2<T, S> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_tuple("UnitVector2").field(&self.0).finish()
+        }
+    }
+}
+
+pub mod vector3 {
+    use crate::scalar::{Real, Scalar};
+
+    #[derive(Debug, Copy, Clone, PartialEq)]
+    pub struct Vector3<T = f32> {
+        x: T,
+        y: T,
+        z: T,
+    }
+
+    impl<T: Scalar> Vector3<T> {
+        pub fn new(x: T, y: T, z: T) -> Vector3<T> {
+            Vector3{x, y, z}
+        }
+
+        pub fn x(&self) -> T {
+            self.x
+        }
+
+        pub fn y(&self) -> T {
+            self.y
+        }
+
+        pub fn z(&self) -> T {
+            self.z
+        }
+
+        pub fn magnitude_squared(&self) -> T {
+            self.x * self.x + self.y * self.y + self.z * self.z
+        }
+
+        pub fn set_x(&mut self, x: T) {
+            self.x = x;
+        }
+
+        pub fn set_y(&mut self, y: T) {
+            self.y = y;
+        }
+
+        pub fn set_z(&mut self, z: T) {
+            self.z = z;
+        }
+    }
+
+    impl<T: Real> Vector3<T> {
+        /// Computed on demand, prefer `magnitude_squared` when only comparing lengths.
+        pub fn magnitude(&self) -> T {
+            self.magnitude_squared().sqrt()
+        }
+    }
+}
+
+pub mod vector4 {
+    use crate::scalar::{Real, Scalar};
+
+    #[derive(Debug, Copy, Clone, PartialEq)]
+    pub struct Vector4<T = f32> {
+        x: T,
+        y: T,
+        z: T,
+        w: T,
+    }
+
+    impl<T: Scalar> Vector4<T> {
+        pub fn new(x: T, y: T, z: T, w: T) -> Vector4<T> {
+            Vector4{x, y, z, w}
+        }
+
+        pub fn x(&self) -> T {
+            self.x
+        }
+
+        pub fn y(&self) -> T {
+            self.y
+        }
+
+        pub fn z(&self) -> T {
+            self.z
+        }
+
+        pub fn w(&self) -> T {
+            self.w
+        }
+
+        pub fn magnitude_squared(&self) -> T {
+            self.x * self.x + self.y * self.y + self.z * self.z + self.w * self.w
+        }
+
+        pub fn set_x(&mut self, x: T) {
+            self.x = x;
+        }
+
+        pub fn set_y(&mut self, y: T) {
             self.y = y;
-            self.magnitude = magnitude_of(&self);
+        }
+
+        pub fn set_z(&mut self, z: T) {
+            self.z = z;
+        }
+
+        pub fn set_w(&mut self, w: T) {
+            self.w = w;
+        }
+    }
+
+    impl<T: Real> Vector4<T> {
+        /// Computed on demand, prefer `magnitude_squared` when only comparing lengths.
+        pub fn magnitude(&self) -> T {
+            self.magnitude_squared().sqrt()
         }
     }
 }
@@ -48,6 +433,12 @@ mod ops;
 mod core;
 mod quick_hand;
 mod morph;
+mod dimensions;
+mod grid;
+mod validation;
+mod swizzle;
+mod interop;
+mod rotation;
 
 #[cfg(test)]
 mod test_tools;
@@ -55,6 +446,12 @@ mod test_tools;
 #[cfg(test)]
 mod tests {
     use super::vector2::Vector2;
+    use super::point2::Point2;
+    use super::unit_vector2::UnitVector2;
+    use super::vector3::Vector3;
+    use super::vector4::Vector4;
+    use std::hint::black_box;
+    use std::time::{Duration, Instant};
 
     //  _____________________
     //  
@@ -62,7 +459,13 @@ mod tests {
     //  _____________________
     //  Vector2 implements :
     //  - vector components datas encapsulation
-    //  - vector magnitude "propertization"
+    //  - with_x, with_y copies
+    //  - vector magnitude "propertization", computed on demand
+    //  - vector squared magnitude
+    //  - genericity over its Scalar type (f32, f64)
+    //  Vector3 and Vector4 implement the same, with their extra components
+    //  Point2 implements components encapsulation and explicit Vector2 conversions
+    //  UnitVector2 implements a constant magnitude of 1
 
     #[test]
     fn vector2_should_contain_the_right_data() {
@@ -89,6 +492,14 @@ mod tests {
         assert_eq!(4., vec1.y());
     }
 
+    #[test]
+    fn vector2_should_implement_with_xy() {
+        let vec1 = Vector2::new(1., 2.);
+        assert_eq!(Vector2::new(3., 2.), vec1.with_x(3.));
+        assert_eq!(Vector2::new(1., 4.), vec1.with_y(4.));
+        assert_eq!(Vector2::new(1., 2.), vec1);
+    }
+
     #[test]
     fn vector2_magnitude_should_update_on_xy_set() {
         let mut vec1 = Vector2::new(4., 1.);
@@ -98,4 +509,171 @@ mod tests {
         vec1.set_x(3.);
         assert_eq!(5., vec1.magnitude());
     }
+
+    #[test]
+    fn vector2_should_implement_magnitude_squared() {
+        let vec1 = Vector2::new(4., 3.);
+        assert_eq!(25., vec1.magnitude_squared());
+        let mut vec1 = Vector2::new(1., -2.);
+        assert_eq!(5., vec1.magnitude_squared());
+        vec1.set_x(-2.);
+        assert_eq!(8., vec1.magnitude_squared());
+    }
+
+    #[test]
+    fn vector2_should_default_to_f32() {
+        let vec1: Vector2 = Vector2::new(4., 3.);
+        let magnitude: f32 = vec1.magnitude();
+        assert_eq!(5., magnitude);
+    }
+
+    #[test]
+    fn vector2_should_support_f64() {
+        let vec1: Vector2<f64> = Vector2::new(1e-30, 1e-30);
+        assert_eq!(1e-30, vec1.x());
+        assert!(vec1.magnitude() > 0.);
+
+        let mut vec1 = Vector2::new(4_f64, 1.);
+        vec1.set_y(3.);
+        assert_eq!(5., vec1.magnitude());
+    }
+
+    #[test]
+    fn point2_should_contain_the_right_data() {
+        let mut point1 = Point2::new(1., 2.);
+        assert_eq!(1., point1.x());
+        assert_eq!(2., point1.y());
+        point1.set_x(3.);
+        point1.set_y(4.);
+        assert_eq!(Point2::new(3., 4.), point1);
+        assert_eq!(Point2::new(0., 0.), Point2::<f32>::origin());
+    }
+
+    #[test]
+    fn point2_should_convert_explicitly() {
+        let point1 = Point2::new(1., 2.);
+        assert_eq!(Vector2::new(1., 2.), point1.to_vector());
+        assert_eq!(point1, Point2::from_vector(&point1.to_vector()));
+    }
+
+    #[test]
+    fn unit_vector2_should_contain_the_right_data() {
+        let unit1 = UnitVector2::new_unchecked(Vector2::new(0.6, 0.8));
+        assert_eq!(0.6, unit1.x());
+        assert_eq!(0.8, unit1.y());
+        assert_eq!(1., unit1.magnitude());
+        assert_eq!(Vector2::new(0.6, 0.8), unit1.to_vector());
+        assert_eq!(&Vector2::new(0.6, 0.8), unit1.as_vector());
+        assert_eq!(Vector2::new(1., 0.), UnitVector2::<f32>::x_axis().to_vector());
+        assert_eq!(Vector2::new(0., 1.), UnitVector2::<f32>::y_axis().to_vector());
+    }
+
+    #[test]
+    fn vector3_should_contain_the_right_data() {
+        let mut vec1 = Vector3::new(1., 2., 3.);
+        assert_eq!(1., vec1.x());
+        assert_eq!(2., vec1.y());
+        assert_eq!(3., vec1.z());
+        vec1.set_x(4.);
+        vec1.set_y(5.);
+        vec1.set_z(6.);
+        assert_eq!(Vector3::new(4., 5., 6.), vec1);
+    }
+
+    #[test]
+    fn vector3_should_implement_magnitude() {
+        let mut vec1 = Vector3::new(2., 3., 6.);
+        assert_eq!(49., vec1.magnitude_squared());
+        assert_eq!(7., vec1.magnitude());
+        vec1.set_z(0.);
+        assert_eq!(13., vec1.magnitude_squared());
+    }
+
+    #[test]
+    fn vector4_should_contain_the_right_data() {
+        let mut vec1 = Vector4::new(1., 2., 3., 4.);
+        assert_eq!(1., vec1.x());
+        assert_eq!(2., vec1.y());
+        assert_eq!(3., vec1.z());
+        assert_eq!(4., vec1.w());
+        vec1.set_x(5.);
+        vec1.set_y(6.);
+        vec1.set_z(7.);
+        vec1.set_w(8.);
+        assert_eq!(Vector4::new(5., 6., 7., 8.), vec1);
+    }
+
+    #[test]
+    fn vector4_should_implement_magnitude() {
+        let mut vec1 = Vector4::new(1., 1., 1., 1.);
+        assert_eq!(4., vec1.magnitude_squared());
+        assert_eq!(2., vec1.magnitude());
+        vec1.set_w(3.);
+        assert_eq!(12., vec1.magnitude_squared());
+    }
+
+    //  _________________________
+    //  
+    //  MAGNITUDE MICRO-BENCHMARK
+    //  _________________________
+    //  Compares additions on the former eagerly cached layout against the
+    //  current on demand magnitude.
+    //  Run with : cargo test --release -- --ignored --nocapture
+
+    #[derive(Copy, Clone)]
+    struct CachedVector2 {
+        x: f32,
+        y: f32,
+        magnitude: f32,
+    }
+
+    impl CachedVector2 {
+        fn new(x: f32, y: f32) -> CachedVector2 {
+            CachedVector2{x, y, magnitude: (x.powf(2.) + y.powf(2.)).sqrt()}
+        }
+    }
+
+    const BENCH_ITERATIONS: usize = 10_000_000;
+
+    fn bench<F: FnMut(usize)>(label: &str, mut f: F) -> Duration {
+        let start = Instant::now();
+        for i in 0..BENCH_ITERATIONS {
+            f(i);
+        }
+        let elapsed = start.elapsed();
+        println!("{:<32}{:?}", label, elapsed);
+        elapsed
+    }
+
+    #[test]
+    #[ignore]
+    fn vector2_magnitude_benchmark() {
+        let step = 0.5;
+
+        let mut before = CachedVector2::new(0., 0.);
+        bench("before - cached on construction", |i| {
+            let k = black_box(i as f32 * step);
+            before = CachedVector2::new(before.x + k, before.y + k);
+        });
+        black_box(before.magnitude);
+
+        let mut after = Vector2::new(0., 0.);
+        bench("after - computed on demand", |i| {
+            let k = black_box(i as f32 * step);
+            after += Vector2::new(k, k);
+        });
+        black_box(after.magnitude());
+
+        let mut lengths = 0.;
+        bench("after - magnitude read", |i| {
+            lengths += black_box(Vector2::new(i as f32, step)).magnitude();
+        });
+        black_box(lengths);
+
+        let mut lengths = 0.;
+        bench("after - magnitude_squared read", |i| {
+            lengths += black_box(Vector2::new(i as f32, step)).magnitude_squared();
+        });
+        black_box(lengths);
+    }
 }
@@ -1,10 +1,43 @@
+#[allow(clippy::module_inception)]
 mod morph {
     use crate::vector2::Vector2;
+    use crate::unit_vector2::UnitVector2;
+    use crate::scalar::Real;
 
-    impl Vector2 {
+    impl<T: Real, S> Vector2<T, S> {
         pub fn normalize(&mut self) {
             *self = self.normalized();
         }
+
+        /// Leaves `self` untouched and returns `false` on a zero length `other`.
+        pub fn project_on(&mut self, other: &Self) -> bool {
+            if let Some(projection) = self.projected_on(other) { *self = projection; true }
+            else { false }
+        }
+
+        /// Leaves `self` untouched and returns `false` on a zero length `other`.
+        pub fn reject_from(&mut self, other: &Self) -> bool {
+            if let Some(rejection) = self.rejected_from(other) { *self = rejection; true }
+            else { false }
+        }
+
+        pub fn reflect(&mut self, normal: &UnitVector2<T, S>) {
+            *self = self.reflected(normal);
+        }
+
+        pub fn rotate(&mut self, radians: T) {
+            *self = self.rotated(radians);
+        }
+
+        pub fn rotate_around(&mut self, pivot: &Self, radians: T) {
+            *self = self.rotated_around(pivot, radians);
+        }
+
+        /// Leaves `self` untouched and returns `false` on total internal reflection.
+        pub fn refract(&mut self, normal: &UnitVector2<T, S>, eta: T) -> bool {
+            if let Some(refraction) = self.refracted(normal, eta) { *self = refraction; true }
+            else { false }
+        }
     }
 }
 
@@ -16,10 +49,17 @@ mod tests {
     //  ______________________
     //  Vector2 Implements :
     //  - normalize     (Self)
-    //  - project_on    (Self, Self)
+    //  - project_on    (Self, Self) -> bool
+    //  - reject_from   (Self, Self) -> bool
+    //  - reflect       (Self, UnitVector2)
+    //  - refract       (Self, UnitVector2, f32) -> bool
+    //  - rotate        (Self, f32)
+    //  - rotate_around (Self, Self, f32)
 
     use crate::vector2::Vector2;
+    use crate::unit_vector2::UnitVector2;
     use crate::test_tools;
+    use std::f32::consts::PI;
 
     #[test]
     fn vector2_should_implement_normalize() {
@@ -37,4 +77,47 @@ mod tests {
         vec1.normalize();
         test_tools::assert_approx_eq!(1., &vec1.magnitude());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn vector2_should_implement_project_on() {
+        let mut vec1 = Vector2::new(3., 4.);
+        assert!(vec1.project_on(&Vector2::new(0., 2.)));
+        assert_eq!(Vector2::new(0., 4.), vec1);
+
+        assert!(!vec1.project_on(&Vector2::new(0., 0.)));
+        assert_eq!(Vector2::new(0., 4.), vec1);
+    }
+
+    #[test]
+    fn vector2_should_implement_reject_from() {
+        let mut vec1 = Vector2::new(3., 4.);
+        assert!(vec1.reject_from(&Vector2::new(0., 2.)));
+        assert_eq!(Vector2::new(3., 0.), vec1);
+
+        assert!(!vec1.reject_from(&Vector2::new(0., 0.)));
+        assert_eq!(Vector2::new(3., 0.), vec1);
+    }
+
+    #[test]
+    fn vector2_should_implement_reflect_and_refract() {
+        let mut vec1 = Vector2::new(2., -3.);
+        vec1.reflect(&UnitVector2::y_axis());
+        assert_eq!(Vector2::new(2., 3.), vec1);
+
+        let mut vec1 = Vector2::new(1., -0.1);
+        assert!(!vec1.refract(&UnitVector2::y_axis(), 1.5));
+        assert_eq!(Vector2::new(1., -0.1), vec1);
+        assert!(vec1.refract(&UnitVector2::y_axis(), 1.));
+        crate::assert_vec_approx_eq!(Vector2::new(1., -0.1), vec1);
+    }
+
+    #[test]
+    fn vector2_should_implement_rotate() {
+        let mut vec1 = Vector2::new(3., 1.);
+        vec1.rotate(PI/2.);
+        assert_eq!(Vector2::new(-1., 3.), vec1);
+
+        vec1.rotate_around(&Vector2::new(-1., 0.), PI);
+        assert_eq!(Vector2::new(-1., -3.), vec1);
+    }
+}
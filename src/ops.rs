@@ -1,215 +1,386 @@
 pub mod self_ops {
     use crate::vector2::Vector2;
+    use crate::vector3::Vector3;
+    use crate::vector4::Vector4;
+    use crate::scalar::Scalar;
     use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign, Rem, RemAssign};
 
-    impl Add for Vector2 {
-        type Output = Self;
-    
-        fn add(self, other: Self) -> Self {
-            Vector2::new(
-                self.x() + other.x(),
-                self.y() + other.y()
-            )
-        }
-    }
-    
-    impl AddAssign for Vector2 {
-        fn add_assign(&mut self, other: Self) {
-            *self = Vector2::new(
-                self.x() + other.x(),
-                self.y() + other.y()
-            );
-        }
+    macro_rules! impl_self_op {
+        ($V: ident [$($S: ident)?], $new: ident { $($c: ident),+ }, $Op: ident, $op: ident, $OpAssign: ident, $op_assign: ident, $sym: tt) => {
+            impl<T: Scalar $(, $S)?> $Op for $V<T $(, $S)?> {
+                type Output = Self;
+
+                fn $op(self, other: Self) -> Self {
+                    $V::$new(
+                        $(self.$c() $sym other.$c()),+
+                    )
+                }
+            }
+
+            impl<T: Scalar $(, $S)?> $OpAssign for $V<T $(, $S)?> {
+                fn $op_assign(&mut self, other: Self) {
+                    *self = $V::$new(
+                        $(self.$c() $sym other.$c()),+
+                    );
+                }
+            }
+        };
     }
 
-    impl Sub for Vector2 {
-        type Output = Self;
-    
-        fn sub(self, other: Self) -> Self {
-            Vector2::new(
-                self.x() - other.x(),
-                self.y() - other.y()
-            )
-        }
+    macro_rules! impl_self_ops {
+        ($V: ident [$($S: ident)?], $new: ident { $($c: ident),+ }) => {
+            impl_self_op!($V [$($S)?], $new { $($c),+ }, Add, add, AddAssign, add_assign, +);
+            impl_self_op!($V [$($S)?], $new { $($c),+ }, Sub, sub, SubAssign, sub_assign, -);
+            impl_self_op!($V [$($S)?], $new { $($c),+ }, Div, div, DivAssign, div_assign, /);
+            impl_self_op!($V [$($S)?], $new { $($c),+ }, Mul, mul, MulAssign, mul_assign, *);
+            impl_self_op!($V [$($S)?], $new { $($c),+ }, Rem, rem, RemAssign, rem_assign, %);
+        };
     }
-    
-    impl SubAssign for Vector2 {
-        fn sub_assign(&mut self, other: Self) {
-            *self = Vector2::new(
-                self.x() - other.x(),
-                self.y() - other.y()
-            );
-        }
+
+    impl_self_ops!(Vector2 [S], in_space { x, y });
+    impl_self_ops!(Vector3 [], new { x, y, z });
+    impl_self_ops!(Vector4 [], new { x, y, z, w });
+}
+
+pub mod k_ops {
+    use crate::vector2::Vector2;
+    use crate::vector3::Vector3;
+    use crate::vector4::Vector4;
+    use crate::scalar::Scalar;
+    use std::ops::{Add, AddAssign, Sub, SubAssign, Div, DivAssign, Mul, MulAssign, Rem, RemAssign};
+
+    macro_rules! impl_k_op {
+        ($V: ident [$($S: ident)?], $new: ident { $($c: ident),+ }, $Op: ident, $op: ident, $OpAssign: ident, $op_assign: ident, $sym: tt) => {
+            impl<T: Scalar $(, $S)?> $Op<T> for $V<T $(, $S)?> {
+                type Output = Self;
+
+                fn $op(self, k: T) -> Self {
+                    $V::$new(
+                        $(self.$c() $sym k),+
+                    )
+                }
+            }
+
+            impl<T: Scalar $(, $S)?> $OpAssign<T> for $V<T $(, $S)?> {
+                fn $op_assign(&mut self, k: T) {
+                    *self = $V::$new(
+                        $(self.$c() $sym k),+
+                    );
+                }
+            }
+        };
     }
-    
-    impl Div for Vector2 {
-        type Output = Self;
-    
-        fn div(self, other: Self) -> Self {
-            Vector2::new(
-                self.x() / other.x(),
-                self.y() / other.y()
-            )
-        }
+
+    macro_rules! impl_k_ops {
+        ($V: ident [$($S: ident)?], $new: ident { $($c: ident),+ }) => {
+            impl_k_op!($V [$($S)?], $new { $($c),+ }, Add, add, AddAssign, add_assign, +);
+            impl_k_op!($V [$($S)?], $new { $($c),+ }, Sub, sub, SubAssign, sub_assign, -);
+            impl_k_op!($V [$($S)?], $new { $($c),+ }, Div, div, DivAssign, div_assign, /);
+            impl_k_op!($V [$($S)?], $new { $($c),+ }, Mul, mul, MulAssign, mul_assign, *);
+            impl_k_op!($V [$($S)?], $new { $($c),+ }, Rem, rem, RemAssign, rem_assign, %);
+        };
     }
 
-    impl DivAssign for Vector2 {
-        fn div_assign(&mut self, other: Self) {
-            *self = Vector2::new(
-                self.x() / other.x(),
-                self.y() / other.y()
-            );
-        }
+    impl_k_ops!(Vector2 [S], in_space { x, y });
+    impl_k_ops!(Vector3 [], new { x, y, z });
+    impl_k_ops!(Vector4 [], new { x, y, z, w });
+}
+
+pub mod neg_ops {
+    use crate::vector2::Vector2;
+    use crate::vector3::Vector3;
+    use crate::vector4::Vector4;
+    use crate::scalar::Scalar;
+    use std::ops::Neg;
+
+    macro_rules! impl_neg {
+        ($V: ident [$($S: ident)?], $new: ident { $($c: ident),+ }) => {
+            impl<T: Scalar + Neg<Output = T> $(, $S)?> Neg for $V<T $(, $S)?> {
+                type Output = Self;
+
+                fn neg(self) -> Self {
+                    $V::$new(
+                        $(-self.$c()),+
+                    )
+                }
+            }
+
+            impl<T: Scalar + Neg<Output = T> $(, $S)?> Neg for &$V<T $(, $S)?> {
+                type Output = $V<T $(, $S)?>;
+
+                fn neg(self) -> $V<T $(, $S)?> {
+                    -*self
+                }
+            }
+        };
     }
 
-    impl Mul for Vector2 {
-        type Output = Self;
-        
-        fn mul(self, other: Self) -> Self {
-            Vector2::new(
-                self.x() * other.x(),
-                self.y() * other.y()
-            )
-        }
+    impl_neg!(Vector2 [S], in_space { x, y });
+    impl_neg!(Vector3 [], new { x, y, z });
+    impl_neg!(Vector4 [], new { x, y, z, w });
+}
+
+pub mod ref_ops {
+    use crate::vector2::Vector2;
+    use crate::vector3::Vector3;
+    use crate::vector4::Vector4;
+    use crate::scalar::Scalar;
+    use std::ops::{Add, AddAssign, Sub, SubAssign, Div, DivAssign, Mul, MulAssign, Rem, RemAssign};
+
+    //  Forwards every reference combination to the by-value operators of self_ops and k_ops.
+    macro_rules! impl_ref_op {
+        ($V: ident [$($S: ident)?], $Op: ident, $op: ident, $OpAssign: ident, $op_assign: ident) => {
+            impl<T: Scalar $(, $S)?> $Op<&$V<T $(, $S)?>> for $V<T $(, $S)?> {
+                type Output = Self;
+
+                fn $op(self, other: &Self) -> Self {
+                    self.$op(*other)
+                }
+            }
+
+            impl<T: Scalar $(, $S)?> $Op<$V<T $(, $S)?>> for &$V<T $(, $S)?> {
+                type Output = $V<T $(, $S)?>;
+
+                fn $op(self, other: $V<T $(, $S)?>) -> $V<T $(, $S)?> {
+                    (*self).$op(other)
+                }
+            }
+
+            impl<T: Scalar $(, $S)?> $Op<&$V<T $(, $S)?>> for &$V<T $(, $S)?> {
+                type Output = $V<T $(, $S)?>;
+
+                fn $op(self, other: &$V<T $(, $S)?>) -> $V<T $(, $S)?> {
+                    (*self).$op(*other)
+                }
+            }
+
+            impl<T: Scalar $(, $S)?> $OpAssign<&$V<T $(, $S)?>> for $V<T $(, $S)?> {
+                fn $op_assign(&mut self, other: &Self) {
+                    self.$op_assign(*other);
+                }
+            }
+
+            impl<T: Scalar $(, $S)?> $Op<&T> for $V<T $(, $S)?> {
+                type Output = Self;
+
+                fn $op(self, k: &T) -> Self {
+                    self.$op(*k)
+                }
+            }
+
+            impl<T: Scalar $(, $S)?> $Op<T> for &$V<T $(, $S)?> {
+                type Output = $V<T $(, $S)?>;
+
+                fn $op(self, k: T) -> $V<T $(, $S)?> {
+                    (*self).$op(k)
+                }
+            }
+
+            impl<T: Scalar $(, $S)?> $Op<&T> for &$V<T $(, $S)?> {
+                type Output = $V<T $(, $S)?>;
+
+                fn $op(self, k: &T) -> $V<T $(, $S)?> {
+                    (*self).$op(*k)
+                }
+            }
+
+            impl<T: Scalar $(, $S)?> $OpAssign<&T> for $V<T $(, $S)?> {
+                fn $op_assign(&mut self, k: &T) {
+                    self.$op_assign(*k);
+                }
+            }
+        };
     }
 
-    impl MulAssign for Vector2 {
-        fn mul_assign(&mut self, other: Self) {
-            *self = Vector2::new(
-                self.x() * other.x(),
-                self.y() * other.y()
-            );
-        }
+    macro_rules! impl_ref_ops {
+        ($V: ident [$($S: ident)?]) => {
+            impl_ref_op!($V [$($S)?], Add, add, AddAssign, add_assign);
+            impl_ref_op!($V [$($S)?], Sub, sub, SubAssign, sub_assign);
+            impl_ref_op!($V [$($S)?], Div, div, DivAssign, div_assign);
+            impl_ref_op!($V [$($S)?], Mul, mul, MulAssign, mul_assign);
+            impl_ref_op!($V [$($S)?], Rem, rem, RemAssign, rem_assign);
+        };
     }
 
-    impl Rem for Vector2 {
-        type Output = Self;
+    impl_ref_ops!(Vector2 [S]);
+    impl_ref_ops!(Vector3 []);
+    impl_ref_ops!(Vector4 []);
+}
 
-        fn rem(self, other: Self) -> Self {
-            Vector2::new(
-                self.x() % other.x(),
-                self.y() % other.y(),
-            )
-        }
+pub mod lhs_ops {
+    use crate::vector2::Vector2;
+    use crate::vector3::Vector3;
+    use crate::vector4::Vector4;
+    use crate::fixed::Fixed;
+    use std::ops::{Add, Sub, Div, Mul, Rem};
+
+    //  `k op vector`, the scalar on the left hand side. The orphan rule forbids a
+    //  blanket impl over T, hence one impl per scalar type.
+    macro_rules! impl_lhs_op {
+        ($t: ty, $V: ident [$($S: ident)?], $new: ident { $($c: ident),+ }, $Op: ident, $op: ident, $sym: tt) => {
+            impl$(<$S>)? $Op<$V<$t $(, $S)?>> for $t {
+                type Output = $V<$t $(, $S)?>;
+
+                fn $op(self, vector: $V<$t $(, $S)?>) -> $V<$t $(, $S)?> {
+                    $V::$new(
+                        $(self $sym vector.$c()),+
+                    )
+                }
+            }
+
+            impl$(<$S>)? $Op<&$V<$t $(, $S)?>> for $t {
+                type Output = $V<$t $(, $S)?>;
+
+                fn $op(self, vector: &$V<$t $(, $S)?>) -> $V<$t $(, $S)?> {
+                    self.$op(*vector)
+                }
+            }
+        };
     }
 
-    impl RemAssign for Vector2 {
-        fn rem_assign(&mut self, other: Self) {
-            *self = Vector2::new(
-                self.x() % other.x(),
-                self.y() % other.y(),
-            );
-        }
+    macro_rules! impl_lhs_ops {
+        ($($t: ty),+) => {
+            $(
+                impl_lhs_ops!(@vector $t, Vector2 [S], in_space { x, y });
+                impl_lhs_ops!(@vector $t, Vector3 [], new { x, y, z });
+                impl_lhs_ops!(@vector $t, Vector4 [], new { x, y, z, w });
+            )+
+        };
+        (@vector $t: ty, $V: ident [$($S: ident)?], $new: ident { $($c: ident),+ }) => {
+            impl_lhs_op!($t, $V [$($S)?], $new { $($c),+ }, Add, add, +);
+            impl_lhs_op!($t, $V [$($S)?], $new { $($c),+ }, Sub, sub, -);
+            impl_lhs_op!($t, $V [$($S)?], $new { $($c),+ }, Div, div, /);
+            impl_lhs_op!($t, $V [$($S)?], $new { $($c),+ }, Mul, mul, *);
+            impl_lhs_op!($t, $V [$($S)?], $new { $($c),+ }, Rem, rem, %);
+        };
     }
+
+    impl_lhs_ops!(f32, f64, i8, i16, i32, i64, isize, u8, u16, u32, u64, usize, Fixed);
 }
 
-pub mod k_ops {
+pub mod iter_ops {
     use crate::vector2::Vector2;
-    use std::ops::{Add, AddAssign, Sub, SubAssign, Div, DivAssign, Mul, MulAssign, Rem, RemAssign};
+    use crate::vector3::Vector3;
+    use crate::vector4::Vector4;
+    use crate::scalar::Scalar;
+    use std::iter::{Product, Sum};
 
-    impl Sub<f32> for Vector2 {
-        type Output = Self;
-    
-        fn sub(self, k: f32) -> Self {
-            Vector2::new(
-                self.x() - k,
-                self.y() - k
-            )
-        }
+    //  One `$k` per component.
+    macro_rules! splat {
+        ($c: ident, $k: expr) => { $k };
     }
 
-    impl SubAssign<f32> for Vector2 {    
-        fn sub_assign(&mut self, k: f32) {
-            *self = Vector2::new(
-                self.x() - k,
-                self.y() - k
-            );
-        }
-    }
+    macro_rules! impl_iter_ops {
+        ($V: ident [$($S: ident)?], $new: ident { $($c: ident),+ }) => {
+            impl<T: Scalar $(, $S)?> Sum for $V<T $(, $S)?> {
+                fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                    iter.fold($V::$new($(splat!($c, T::ZERO)),+), |sum, v| sum + v)
+                }
+            }
 
-    impl Add<f32> for Vector2 {
-        type Output = Self;
-    
-        fn add(self, k: f32) -> Self {
-            Vector2::new(
-                self.x() + k,
-                self.y() + k
-            )
-        }
+            impl<'a, T: Scalar $(, $S)?> Sum<&'a Self> for $V<T $(, $S)?> {
+                fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+                    iter.copied().sum()
+                }
+            }
+
+            impl<T: Scalar $(, $S)?> Product for $V<T $(, $S)?> {
+                fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+                    iter.fold($V::$new($(splat!($c, T::ONE)),+), |product, v| product * v)
+                }
+            }
+
+            impl<'a, T: Scalar $(, $S)?> Product<&'a Self> for $V<T $(, $S)?> {
+                fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+                    iter.copied().product()
+                }
+            }
+        };
     }
 
-    impl AddAssign<f32> for Vector2 {    
-        fn add_assign(&mut self, k: f32) {
-            *self = Vector2::new(
-                self.x() + k,
-                self.y() + k
-            );
+    impl_iter_ops!(Vector2 [S], in_space { x, y });
+    impl_iter_ops!(Vector3 [], new { x, y, z });
+    impl_iter_ops!(Vector4 [], new { x, y, z, w });
+}
+
+pub mod affine_ops {
+    use crate::point2::Point2;
+    use crate::vector2::Vector2;
+    use crate::scalar::Scalar;
+    use std::ops::{Add, AddAssign, Sub, SubAssign};
+
+    impl<T: Scalar, S> Sub for Point2<T, S> {
+        type Output = Vector2<T, S>;
+
+        fn sub(self, other: Self) -> Vector2<T, S> {
+            Vector2::in_space(
+                self.x() - other.x(),
+                self.y() - other.y()
+            )
         }
     }
 
-    impl Div<f32> for Vector2 {
+    impl<T: Scalar, S> Add<Vector2<T, S>> for Point2<T, S> {
         type Output = Self;
-    
-        fn div(self, k: f32) -> Self {
-            Vector2::new(
-                self.x() / k,
-                self.y() / k
+
+        fn add(self, vector: Vector2<T, S>) -> Self {
+            Point2::in_space(
+                self.x() + vector.x(),
+                self.y() + vector.y()
             )
         }
     }
-    
-    impl DivAssign<f32> for Vector2 {
-        fn div_assign(&mut self, k: f32) {
-            *self = Vector2::new(
-                self.x() / k,
-                self.y() / k
-            );
+
+    impl<T: Scalar, S> AddAssign<Vector2<T, S>> for Point2<T, S> {
+        fn add_assign(&mut self, vector: Vector2<T, S>) {
+            *self = *self + vector;
         }
     }
-    
-    impl Mul<f32> for Vector2 {
+
+    impl<T: Scalar, S> Sub<Vector2<T, S>> for Point2<T, S> {
         type Output = Self;
-    
-        fn mul(self, k: f32) -> Self {
-            Vector2::new(
-                self.x() * k,
-                self.y() * k
+
+        fn sub(self, vector: Vector2<T, S>) -> Self {
+            Point2::in_space(
+                self.x() - vector.x(),
+                self.y() - vector.y()
             )
         }
     }
-    
-    impl MulAssign<f32> for Vector2 {
-        fn mul_assign(&mut self, k: f32) {
-            *self = Vector2::new(
-                self.x() * k,
-                self.y() * k
-            );
+
+    impl<T: Scalar, S> SubAssign<Vector2<T, S>> for Point2<T, S> {
+        fn sub_assign(&mut self, vector: Vector2<T, S>) {
+            *self = *self - vector;
         }
     }
+}
 
-    impl Rem<f32> for Vector2 {
-        type Output = Self;
+pub mod unit_ops {
+    use crate::unit_vector2::UnitVector2;
+    use crate::vector2::Vector2;
+    use crate::scalar::Real;
+    use std::ops::{Mul, Neg};
 
-        fn rem(self, k: f32) -> Self {
-            Vector2::new(
-                self.x() % k,
-                self.y() % k
-            )
+    impl<T: Real, S> Mul<T> for UnitVector2<T, S> {
+        type Output = Vector2<T, S>;
+
+        fn mul(self, k: T) -> Vector2<T, S> {
+            self.to_vector() * k
         }
     }
 
-    impl RemAssign<f32> for Vector2 {
-        fn rem_assign(&mut self, k: f32) {
-            *self = Vector2::new(
-                self.x() % k,
-                self.y() % k
-            );
+    impl<T: Real, S> Neg for UnitVector2<T, S> {
+        type Output = Self;
+
+        fn neg(self) -> Self {
+            UnitVector2::new_unchecked(-self.to_vector())
         }
     }
 }
 
 
 #[cfg(test)]
+#[allow(clippy::assign_op_pattern)]
 mod tests {
     //  ____________________
     //
@@ -220,9 +391,27 @@ mod tests {
     //  - Sub, SubAssign    (Self, Self)
     //  - Div, DivAssign    (Self, Self), (Self, f32)
     //  - Mul, MulAssign    (Self, Self), (Self, f32)
+    //  - Rem, RemAssign    (Self, Self), (Self, f32)
+    //  - Neg               (Self) -> Self
+    //  - Every operator also takes &Self and &f32 operands
+    //  - Add, Sub, Div, Mul, Rem   (f32, Self) -> Self, for every scalar type
+    //  - Sum, Product      Iterator<Self>, Iterator<&Self>
     //  - PartialEq         - Derived
+    //  - Every operator is generic over the Scalar type (f32, f64, integers)
+    //  - Vector3 and Vector4 share the same operator set
+    //  Point2 Implements :
+    //  - Sub               (Point2, Point2) -> Vector2
+    //  - Add, AddAssign    (Point2, Vector2) -> Point2
+    //  - Sub, SubAssign    (Point2, Vector2) -> Point2
+    //  UnitVector2 Implements :
+    //  - Mul               (UnitVector2, f32) -> Vector2
+    //  - Neg               (UnitVector2) -> UnitVector2
 
     use crate::vector2::Vector2;
+    use crate::point2::Point2;
+    use crate::unit_vector2::UnitVector2;
+    use crate::vector3::Vector3;
+    use crate::vector4::Vector4;
     
     #[test]
     fn vector2_should_implement_k_add() {
@@ -409,4 +598,177 @@ mod tests {
         vec1 %= 3.;
         assert_eq!(vec1, Vector2::new(-1., -1.));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn vector2_operators_should_support_f64() {
+        let vec1 = Vector2::new(4_f64, 2.);
+        let vec2 = Vector2::new(2_f64, 4.);
+        assert_eq!(vec1 + vec2, Vector2::new(6., 6.));
+        assert_eq!(vec1 - vec2, Vector2::new(2., -2.));
+        assert_eq!(vec1 * vec2, Vector2::new(8., 8.));
+        assert_eq!(vec1 / vec2, Vector2::new(2., 0.5));
+        assert_eq!(vec1 % 3., Vector2::new(1., 2.));
+    }
+
+    #[test]
+    fn vector3_should_implement_operators() {
+        let vec1 = Vector3::new(4., 2., 6.);
+        let vec2 = Vector3::new(2., 4., 4.);
+        assert_eq!(vec1 + vec2, Vector3::new(6., 6., 10.));
+        assert_eq!(vec1 - vec2, Vector3::new(2., -2., 2.));
+        assert_eq!(vec1 * vec2, Vector3::new(8., 8., 24.));
+        assert_eq!(vec1 / vec2, Vector3::new(2., 0.5, 1.5));
+        assert_eq!(vec1 % vec2, Vector3::new(0., 2., 2.));
+
+        let mut vec1 = Vector3::new(4., 2., 6.);
+        vec1 += vec2;
+        assert_eq!(vec1, Vector3::new(6., 6., 10.));
+        vec1 /= Vector3::new(3., 2., 5.);
+        assert_eq!(vec1, Vector3::new(2., 3., 2.));
+    }
+
+    #[test]
+    fn vector3_should_implement_k_operators() {
+        let vec1 = Vector3::new(4., 2., 6.);
+        assert_eq!(vec1 + 1., Vector3::new(5., 3., 7.));
+        assert_eq!(vec1 - 1., Vector3::new(3., 1., 5.));
+        assert_eq!(vec1 * 2., Vector3::new(8., 4., 12.));
+        assert_eq!(vec1 / 2., Vector3::new(2., 1., 3.));
+        assert_eq!(vec1 % 4., Vector3::new(0., 2., 2.));
+
+        let mut vec1 = Vector3::new(4., 2., 6.);
+        vec1 *= 3.;
+        assert_eq!(vec1, Vector3::new(12., 6., 18.));
+    }
+
+    #[test]
+    fn vector4_should_implement_operators() {
+        let vec1 = Vector4::new(4., 2., 6., 1.);
+        let vec2 = Vector4::new(2., 4., 4., 2.);
+        assert_eq!(vec1 + vec2, Vector4::new(6., 6., 10., 3.));
+        assert_eq!(vec1 - vec2, Vector4::new(2., -2., 2., -1.));
+        assert_eq!(vec1 * vec2, Vector4::new(8., 8., 24., 2.));
+        assert_eq!(vec1 / vec2, Vector4::new(2., 0.5, 1.5, 0.5));
+        assert_eq!(vec1 % vec2, Vector4::new(0., 2., 2., 1.));
+
+        let mut vec1 = Vector4::new(4., 2., 6., 1.);
+        vec1 -= vec2;
+        assert_eq!(vec1, Vector4::new(2., -2., 2., -1.));
+    }
+
+    #[test]
+    fn vector4_should_implement_k_operators() {
+        let vec1 = Vector4::new(4., 2., 6., 1.);
+        assert_eq!(vec1 + 1., Vector4::new(5., 3., 7., 2.));
+        assert_eq!(vec1 - 1., Vector4::new(3., 1., 5., 0.));
+        assert_eq!(vec1 * 2., Vector4::new(8., 4., 12., 2.));
+        assert_eq!(vec1 / 2., Vector4::new(2., 1., 3., 0.5));
+        assert_eq!(vec1 % 4., Vector4::new(0., 2., 2., 1.));
+
+        let mut vec1 = Vector4::new(4., 2., 6., 1.);
+        vec1 %= 4.;
+        assert_eq!(vec1, Vector4::new(0., 2., 2., 1.));
+    }
+
+    #[test]
+    fn point2_should_implement_point_sub() {
+        let point1 = Point2::new(4., 2.);
+        let point2 = Point2::new(1., 3.);
+        assert_eq!(Vector2::new(3., -1.), point1 - point2);
+        assert_eq!(Vector2::new(-3., 1.), point2 - point1);
+    }
+
+    #[test]
+    fn point2_should_implement_vector_add() {
+        let mut point1 = Point2::new(4., 2.);
+        let vec1 = Vector2::new(1., -3.);
+        assert_eq!(Point2::new(5., -1.), point1 + vec1);
+        point1 += vec1;
+        assert_eq!(Point2::new(5., -1.), point1);
+    }
+
+    #[test]
+    fn point2_should_implement_vector_sub() {
+        let mut point1 = Point2::new(4., 2.);
+        let vec1 = Vector2::new(1., -3.);
+        assert_eq!(Point2::new(3., 5.), point1 - vec1);
+        point1 -= vec1;
+        assert_eq!(Point2::new(3., 5.), point1);
+    }
+
+    #[test]
+    fn unit_vector2_should_implement_k_mul() {
+        let unit1 = UnitVector2::new_unchecked(Vector2::new(0.6, 0.8));
+        assert_eq!(Vector2::new(3., 4.), unit1 * 5.);
+    }
+
+    #[test]
+    fn unit_vector2_should_implement_neg() {
+        let unit1 = UnitVector2::new_unchecked(Vector2::new(0.6, -0.8));
+        assert_eq!(Vector2::new(-0.6, 0.8), (-unit1).to_vector());
+        assert_eq!(1., (-unit1).magnitude());
+    }
+
+    #[test]
+    fn vector2_should_implement_neg() {
+        let vec1 = Vector2::new(4., -2.);
+        assert_eq!(Vector2::new(-4., 2.), -vec1);
+        assert_eq!(Vector2::new(-4., 2.), -&vec1);
+        assert_eq!(Vector2::<i32>::new(-1, 3), -Vector2::<i32>::new(1, -3));
+        assert_eq!(Vector3::new(-1., 2., -3.), -Vector3::new(1., -2., 3.));
+        assert_eq!(Vector4::new(-1., 2., -3., 0.), -Vector4::new(1., -2., 3., 0.));
+    }
+
+    #[test]
+    fn vector2_should_implement_lhs_k_ops() {
+        let vec1 = Vector2::new(4., 2.);
+        assert_eq!(Vector2::new(12., 6.), 3. * vec1);
+        assert_eq!(Vector2::new(1., 2.), 4. / vec1);
+        assert_eq!(Vector2::new(5., 3.), 1. + vec1);
+        assert_eq!(Vector2::new(-3., -1.), 1. - vec1);
+        assert_eq!(Vector2::new(3., 1.), 7. % vec1);
+        assert_eq!(Vector2::new(12., 6.), 3. * &vec1);
+
+        assert_eq!(Vector2::new(3_f64, 6.), 3. * Vector2::new(1_f64, 2.));
+        assert_eq!(Vector2::<i32>::new(3, 2), 6 / Vector2::<i32>::new(2, 3));
+        assert_eq!(Vector3::new(2., 4., 6.), 2. * Vector3::new(1., 2., 3.));
+        assert_eq!(Vector4::new(2., 1., 0.5, 0.25), 1. / Vector4::new(0.5, 1., 2., 4.));
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn vector2_should_implement_ref_ops() {
+        let vec1 = Vector2::new(4., 2.);
+        let vec2 = Vector2::new(2., 4.);
+        assert_eq!(Vector2::new(6., 6.), &vec1 + &vec2);
+        assert_eq!(Vector2::new(2., -2.), &vec1 - vec2);
+        assert_eq!(Vector2::new(8., 8.), vec1 * &vec2);
+        assert_eq!(Vector2::new(2., 0.5), &vec1 / &vec2);
+        assert_eq!(Vector2::new(0., 2.), &vec1 % &vec2);
+        assert_eq!(Vector2::new(8., 4.), &vec1 * 2.);
+        assert_eq!(Vector2::new(1., 2.), vec1 % &3.);
+
+        let mut vec3 = vec1;
+        vec3 += &vec2;
+        vec3 *= &2.;
+        vec3 %= &Vector2::new(5., 5.);
+        assert_eq!(Vector2::new(2., 2.), vec3);
+    }
+
+    #[test]
+    fn vector2_should_implement_sum() {
+        let vectors = [Vector2::new(1., 2.), Vector2::new(3., 4.), Vector2::new(-1., 0.5)];
+        assert_eq!(Vector2::new(3., 6.5), vectors.iter().sum());
+        assert_eq!(Vector2::new(3., 6.5), vectors.into_iter().sum());
+        assert_eq!(Vector2::new(0., 0.), Vec::<Vector2>::new().into_iter().sum());
+        assert_eq!(Vector3::new(2., 2., 2.), [Vector3::new(1., 1., 1.); 2].iter().sum());
+    }
+
+    #[test]
+    fn vector2_should_implement_product() {
+        let vectors = [Vector2::new(1., 2.), Vector2::new(3., 4.), Vector2::new(-1., 0.5)];
+        assert_eq!(Vector2::new(-3., 4.), vectors.iter().product());
+        assert_eq!(Vector2::new(1., 1.), Vec::<Vector2>::new().into_iter().product());
+        assert_eq!(Vector4::new(4., 4., 4., 4.), [Vector4::new(2., 2., 2., 2.); 2].into_iter().product());
+    }
+}
@@ -0,0 +1,144 @@
+use crate::angle::wrap_signed;
+use crate::rotation::rotations::sin_cos;
+use crate::scalar::Real;
+use crate::vector2::Vector2;
+
+/// Polar form of a `Vector2`, `theta` being counter-clockwise from the x axis.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct Polar2<T = f32> {
+    pub radius: T,
+    pub theta: T,
+}
+
+impl<T: Real> Polar2<T> {
+    pub fn new(radius: T, theta: T) -> Self {
+        Polar2{radius, theta}
+    }
+
+    /// `theta` in (-PI, PI], a zero vector gives a zero `theta`.
+    pub fn from_vector<S>(vector: &Vector2<T, S>) -> Self {
+        Polar2::new(vector.magnitude(), vector.to_angle())
+    }
+
+    /// Exact for whole quarter turns, as `Vector2::rotated`.
+    pub fn to_vector<S>(&self) -> Vector2<T, S> {
+        let (sin, cos) = sin_cos(self.theta);
+        Vector2::in_space(self.radius * cos, self.radius * sin)
+    }
+
+    /// Same point with a positive radius and `theta` in (-PI, PI].
+    pub fn normalized(&self) -> Self {
+        let theta = wrap_signed(self.theta);
+        if self.radius >= T::ZERO { return Polar2::new(self.radius, theta); }
+
+        if theta > T::ZERO { Polar2::new(-self.radius, theta - T::PI) }
+        else { Polar2::new(-self.radius, theta + T::PI) }
+    }
+
+    /// Interpolates the radius linearly and `theta` along the shortest arc,
+    /// the result is normalized. A zero radius end takes the direction of the other end.
+    pub fn lerp(&self, other: &Self, t: T) -> Self {
+        let (from, to) = (self.normalized(), other.normalized());
+        let from_theta = if from.radius == T::ZERO { to.theta } else { from.theta };
+        let to_theta = if to.radius == T::ZERO { from_theta } else { to.theta };
+
+        Polar2::new(
+            to.radius * t + from.radius * (T::ONE - t),
+            wrap_signed(from_theta + wrap_signed(to_theta - from_theta) * t)
+        )
+    }
+}
+
+impl<T: Real, S> Vector2<T, S> {
+    pub fn to_polar(&self) -> Polar2<T> {
+        Polar2::from_vector(self)
+    }
+}
+
+impl<T: Real, S> From<Vector2<T, S>> for Polar2<T> {
+    fn from(vector: Vector2<T, S>) -> Self {
+        Polar2::from_vector(&vector)
+    }
+}
+
+impl<T: Real, S> From<Polar2<T>> for Vector2<T, S> {
+    fn from(polar: Polar2<T>) -> Self {
+        polar.to_vector()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    //  ____________________________
+    //
+    //  TESTS OVER POLAR COORDINATES
+    //  ____________________________
+    //  Polar2 Implements :
+    //  - from_vector, to_vector    Vector2 <-> Polar2, also through From
+    //  - normalized    (Self) -> Self, positive radius, theta in (-PI, PI]
+    //  - lerp          (Self, Self, f32) -> Self, along the shortest arc
+    //  Vector2 Implements :
+    //  - to_polar      (Self) -> Polar2
+
+    use super::Polar2;
+    use crate::vector2::Vector2;
+    use crate::test_tools;
+    use std::f32::consts::PI;
+
+    #[test]
+    fn polar2_should_convert_from_vector2() {
+        assert_eq!(Polar2::new(5., 0.), Vector2::new(5., 0.).to_polar());
+        assert_eq!(Polar2::new(2., PI/2.), Vector2::new(0., 2.).to_polar());
+        assert_eq!(Polar2::new(1., PI), Vector2::new(-1., 0.).to_polar());
+        assert_eq!(Polar2::new(0., 0.), Polar2::from(Vector2::new(0., 0.)));
+
+        let polar = Vector2::new(1., -1.).to_polar();
+        test_tools::assert_approx_eq!(2_f32.sqrt(), &polar.radius);
+        assert_eq!(-PI/4., polar.theta);
+    }
+
+    #[test]
+    fn polar2_should_convert_to_vector2() {
+        assert_eq!(Vector2::new(0., 3.), Polar2::new(3., PI/2.).to_vector());
+        assert_eq!(Vector2::new(-3., 0.), Vector2::from(Polar2::new(3., -PI)));
+
+        let vec1 = Vector2::new(-2.5, 4.);
+        crate::assert_vec_approx_eq!(vec1, vec1.to_polar().to_vector());
+    }
+
+    #[test]
+    fn polar2_should_implement_normalized() {
+        test_tools::assert_approx_eq!(PI/2., &Polar2::new(2., PI/2. + 4.*PI).normalized().theta);
+        assert_eq!(Polar2::new(2., PI), Polar2::new(2., -PI).normalized());
+        assert_eq!(Polar2::new(2., -PI/2.), Polar2::new(-2., PI/2.).normalized());
+        assert_eq!(Polar2::new(1., 0.1), Polar2::new(1., 0.1).normalized());
+    }
+
+    #[test]
+    fn polar2_should_implement_lerp() {
+        let polar1 = Polar2::new(1., 0.);
+        let polar2 = Polar2::new(3., PI/2.);
+        assert_eq!(Polar2::new(2., PI/4.), polar1.lerp(&polar2, 0.5));
+        assert_eq!(polar1, polar1.lerp(&polar2, 0.));
+        assert_eq!(polar2, polar1.lerp(&polar2, 1.));
+    }
+
+    #[test]
+    fn polar2_lerp_should_take_the_shortest_arc() {
+        let polar1 = Polar2::new(1., 3.*PI/4.);
+        let polar2 = Polar2::new(1., -3.*PI/4.);
+        let middle = polar1.lerp(&polar2, 0.5);
+        test_tools::assert_approx_eq!(PI, &middle.theta.abs());
+
+        let quarter = polar1.lerp(&polar2, 0.25);
+        test_tools::assert_approx_eq!(7.*PI/8., &quarter.theta);
+    }
+
+    #[test]
+    fn polar2_lerp_should_keep_direction_from_zero_radius() {
+        let polar1 = Polar2::new(0., 0.);
+        let polar2 = Polar2::new(4., PI/2.);
+        assert_eq!(Polar2::new(2., PI/2.), polar1.lerp(&polar2, 0.5));
+        assert_eq!(Polar2::new(2., PI/2.), polar2.lerp(&polar1, 0.5));
+    }
+}
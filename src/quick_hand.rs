@@ -1,47 +1,232 @@
+#[allow(clippy::module_inception)]
 pub mod quick_hand {
     use crate::vector2::Vector2;
-    use std::f32::consts::PI;
+    use crate::unit_vector2::UnitVector2;
+    use crate::point2::Point2;
+    use crate::vector3::Vector3;
+    use crate::vector4::Vector4;
+    use crate::scalar::{Float, Real, Scalar};
 
-    impl Vector2 {
-        pub fn wide_angle(&self, other: &Self) -> f32 {
-            let mg_base = self.magnitude() * other.magnitude();
-            if mg_base == 0. { return 0.; }
-    
-            (self.x()*other.y() - self.y()*other.x()).atan2(self.x()*other.x() + self.y()*other.y()).rem_euclid(2.*PI)
+    /// The non NaN of both, as `f32::min`.
+    fn min<T: Scalar>(a: T, b: T) -> T {
+        if a.is_nan() || b < a { b } else { a }
+    }
+
+    fn max<T: Scalar>(a: T, b: T) -> T {
+        if a.is_nan() || b > a { b } else { a }
+    }
+
+    impl<T: Scalar, S> Vector2<T, S> {
+        pub fn map<R: Scalar>(&self, mut f: impl FnMut(T) -> R) -> Vector2<R, S> {
+            Vector2::in_space(f(self.x()), f(self.y()))
         }
-    
-        pub fn distance(&self, other: &Self) -> f32 {
-            (Vector2::new(self.x()-other.x(), self.y() - other.y())).magnitude()
+
+        pub fn zip_with<U: Scalar, R: Scalar>(&self, other: &Vector2<U, S>, mut f: impl FnMut(T, U) -> R) -> Vector2<R, S> {
+            Vector2::in_space(f(self.x(), other.x()), f(self.y(), other.y()))
         }
-    
-        pub fn direction(&self, other: &Self) -> Self {
-            (Vector2::new(other.x()-self.x(), other.y() - self.y())).normalized()
+
+        /// Component-wise, NaN components lose as with `f32::min`.
+        pub fn min(&self, other: &Self) -> Self {
+            self.zip_with(other, min)
+        }
+
+        pub fn max(&self, other: &Self) -> Self {
+            self.zip_with(other, max)
+        }
+
+        /// Component-wise between `lower` and `upper`, NaN components stay NaN.
+        pub fn clamp(&self, lower: &Self, upper: &Self) -> Self {
+            debug_assert!(lower.x() <= upper.x() && lower.y() <= upper.y(), "Vector2::clamp with lower > upper");
+            let clamp = |c: T, lower: T, upper: T| if c < lower { lower } else if c > upper { upper } else { c };
+            Vector2::in_space(clamp(self.x(), lower.x(), upper.x()), clamp(self.y(), lower.y(), upper.y()))
+        }
+
+        pub fn min_element(&self) -> T {
+            min(self.x(), self.y())
+        }
+
+        pub fn max_element(&self) -> T {
+            max(self.x(), self.y())
+        }
+    }
+
+    impl<T: Real, S> Vector2<T, S> {
+        pub fn wide_angle(&self, other: &Self) -> T {
+            self.signed_angle(other).rem_euclid(T::PI + T::PI)
         }
     
-        pub fn to_angle(&self) -> f32 {
+        pub fn to_angle(&self) -> T {
             self.y().atan2(self.x())
         }
 
         pub fn abs(&self) -> Self {
-            Vector2::new(self.x().abs(), self.y().abs())
+            Vector2::in_space(self.x().abs(), self.y().abs())
         }
 
         pub fn fract(&self) -> Self {
-            Vector2::new(self.x().fract(), self.y().fract())
+            Vector2::in_space(self.x().fract(), self.y().fract())
         }
 
-        pub fn rem_euclid(&self, n: f32) -> Self {
-            Vector2::new(self.x().rem_euclid(n), self.y().rem_euclid(n))
+        pub fn rem_euclid(&self, n: T) -> Self {
+            Vector2::in_space(self.x().rem_euclid(n), self.y().rem_euclid(n))
         }
 
         pub fn floor(&self) -> Self {
-            Vector2::new(self.x().floor(), self.y().floor())
+            Vector2::in_space(self.x().floor(), self.y().floor())
+        }
+
+        pub fn ceil(&self) -> Self {
+            Vector2::in_space(self.x().ceil(), self.y().ceil())
+        }
+
+        pub fn round(&self) -> Self {
+            Vector2::in_space(self.x().round(), self.y().round())
+        }
+
+        pub fn trunc(&self) -> Self {
+            Vector2::in_space(self.x().trunc(), self.y().trunc())
+        }
+
+        pub fn sqrt(&self) -> Self {
+            Vector2::in_space(self.x().sqrt(), self.y().sqrt())
+        }
+
+        pub fn recip(&self) -> Self {
+            Vector2::in_space(T::ONE / self.x(), T::ONE / self.y())
+        }
+
+        pub fn lerp(&self, other: &Self, t: T) -> Self {
+            Vector2::in_space(
+                other.x() * t + self.x() * (T::ONE - t),
+                other.y() * t + self.y() * (T::ONE - t)
+            )
+        }
+
+        /// Sweeps the direction of `self` towards the direction of `other` along the shortest arc,
+        /// keeping the magnitude of `self` : only the direction of `other` matters.
+        /// A zero `other` has no direction to sweep to, `self` is returned.
+        pub fn slerp(&self, other: &Self, t: T) -> Self {
+            self.rotated(self.signed_angle(other) * t)
+        }
+
+        /// Normalized `lerp`, (0, 0) when the ends cancel out.
+        pub fn nlerp(&self, other: &Self, t: T) -> Self {
+            self.lerp(other, t).normalized()
+        }
+
+        /// Steps towards `target` by at most `max_delta`, without overshooting.
+        pub fn move_towards(&self, target: &Self, max_delta: T) -> Self {
+            let delta = *target - *self;
+            let distance = delta.magnitude();
+            if distance <= max_delta || distance == T::ZERO { return *target; }
+
+            *self + delta * (max_delta / distance)
+        }
+
+        /// Critically damped spring towards `target`, reaching it in about `smooth_time`.
+        /// `velocity` is the state carried from one call to the next, start it at (0, 0).
+        pub fn smooth_damp(&self, target: &Self, velocity: &mut Self, smooth_time: T, delta_time: T) -> Self {
+            let smooth_time = if smooth_time > T::from_f64(1e-4) { smooth_time } else { T::from_f64(1e-4) };
+            let omega = (T::ONE + T::ONE) / smooth_time;
+            let x = omega * delta_time;
+            let decay = T::ONE / (T::ONE + x + T::from_f64(0.48) * x * x + T::from_f64(0.235) * x * x * x);
+
+            let change = *self - *target;
+            let temp = (*velocity + change * omega) * delta_time;
+            *velocity = (*velocity - temp * omega) * decay;
+            let output = *target + (change + temp) * decay;
+
+            // Landing past the target means it has been reached.
+            if (*target - *self).dot(&(output - *target)) > T::ZERO {
+                *velocity = Vector2::in_space(T::ZERO, T::ZERO);
+                return *target;
+            }
+            output
+        }
+
+        /// Keeps the direction, (0, 0) stays (0, 0) having none.
+        pub fn clamp_magnitude(&self, min: T, max: T) -> Self {
+            let magnitude = self.magnitude();
+            if magnitude == T::ZERO { return *self; }
+
+            if magnitude < min { *self * (min / magnitude) }
+            else if magnitude > max { *self * (max / magnitude) }
+            else { *self }
+        }
+
+        /// Keeps the direction, (0, 0) stays (0, 0) having none.
+        pub fn with_magnitude(&self, magnitude: T) -> Self {
+            self.normalized() * magnitude
+        }
+    }
+
+    impl<T: Float, S> Vector2<T, S> {
+        pub fn signum(&self) -> Self {
+            Vector2::in_space(self.x().signum(), self.y().signum())
+        }
+
+        pub fn powf(&self, n: T) -> Self {
+            Vector2::in_space(self.x().powf(n), self.y().powf(n))
+        }
+
+        pub fn exp(&self) -> Self {
+            Vector2::in_space(self.x().exp(), self.y().exp())
+        }
+
+        /// Magnitudes of `self` with the signs of `sign`.
+        pub fn copysign(&self, sign: &Self) -> Self {
+            Vector2::in_space(self.x().copysign(sign.x()), self.y().copysign(sign.y()))
+        }
+
+        /// `self * a + b` component-wise, with a single rounding.
+        pub fn mul_add(&self, a: &Self, b: &Self) -> Self {
+            Vector2::in_space(self.x().mul_add(a.x(), b.x()), self.y().mul_add(a.y(), b.y()))
+        }
+    }
+
+    impl<T: Real, S> Point2<T, S> {
+        pub fn distance(&self, other: &Self) -> T {
+            (*other - *self).magnitude()
+        }
+
+        /// `None` when both points are the same.
+        pub fn direction(&self, other: &Self) -> Option<UnitVector2<T, S>> {
+            (*other - *self).try_normalize()
+        }
+
+        pub fn lerp(&self, other: &Self, t: T) -> Self {
+            Point2::in_space(
+                other.x() * t + self.x() * (T::ONE - t),
+                other.y() * t + self.y() * (T::ONE - t)
+            )
+        }
+    }
+
+    impl<T: Real> Vector3<T> {
+        pub fn distance(&self, other: &Self) -> T {
+            (*self - *other).magnitude()
+        }
+
+        pub fn lerp(&self, other: &Self, t: T) -> Self {
+            Vector3::new(
+                other.x() * t + self.x() * (T::ONE - t),
+                other.y() * t + self.y() * (T::ONE - t),
+                other.z() * t + self.z() * (T::ONE - t)
+            )
+        }
+    }
+
+    impl<T: Real> Vector4<T> {
+        pub fn distance(&self, other: &Self) -> T {
+            (*self - *other).magnitude()
         }
 
-        pub fn lerp(&self, other: &Self, t: f32) -> Self {
-            Vector2::new(
-                other.x() * t + self.x() * (1. - t),
-                other.y() * t + self.y() * (1. - t)
+        pub fn lerp(&self, other: &Self, t: T) -> Self {
+            Vector4::new(
+                other.x() * t + self.x() * (T::ONE - t),
+                other.y() * t + self.y() * (T::ONE - t),
+                other.z() * t + self.z() * (T::ONE - t),
+                other.w() * t + self.w() * (T::ONE - t)
             )
         }
     }
@@ -53,50 +238,80 @@ mod tests {
     //
     //  TESTS OVER QUICK HAND TOOLS
     //  ___________________________
-    //  Vector2 Implements :
+    //  Point2 Implements :
     //  - distance      (Self, Self) -> f32
-    //  - direction     (Self, Self) -> Self
+    //  - direction     (Self, Self) -> Option<UnitVector2>
+    //  - lerp          (Self, Self, f32) -> Self
+    //  Vector2 Implements :
     //  - wide_angle    (Self, Self) -> f32
     //  - to_angle      (Self) -> f32
+    //  - abs, fract, floor, ceil, round, trunc, sqrt, recip, signum, exp
+    //  - rem_euclid, powf  (Self, f32) -> Self
+    //  - min, max, copysign    (Self, Self) -> Self
+    //  - clamp, mul_add    (Self, Self, Self) -> Self
+    //  - min_element, max_element  (Self) -> f32
+    //  - map           (Self, Fn(f32) -> R) -> Vector2<R>
+    //  - zip_with      (Self, Vector2<U>, Fn(f32, U) -> R) -> Vector2<R>
+    //  - lerp, slerp, nlerp    (Self, Self, f32) -> Self
+    //  - move_towards  (Self, Self, f32) -> Self
+    //  - smooth_damp   (Self, Self, &mut Self, f32, f32) -> Self
+    //  - clamp_magnitude   (Self, f32, f32) -> Self
+    //  - with_magnitude    (Self, f32) -> Self
+    //  Vector3 and Vector4 Implement :
+    //  - distance, lerp
     
     use crate::vector2::Vector2;
+    use crate::vector3::Vector3;
+    use crate::vector4::Vector4;
+    use crate::point2::Point2;
     use std::f32::consts::PI;
     use crate::test_tools;
 
-    fn test_distance_procedure(vec1: Vector2, vec2: Vector2, expected_distance: f32) {
-        let distance_from_vec1 = vec1.distance(&vec2);
-        let distance_from_vec2 = vec2.distance(&vec1);
-        assert_eq!(distance_from_vec1, distance_from_vec2);
-        test_tools::assert_approx_eq!(expected_distance, &distance_from_vec1);
+    fn test_distance_procedure(point1: Point2, point2: Point2, expected_distance: f32) {
+        let distance_from_point1 = point1.distance(&point2);
+        let distance_from_point2 = point2.distance(&point1);
+        assert_eq!(distance_from_point1, distance_from_point2);
+        test_tools::assert_approx_eq!(expected_distance, &distance_from_point1);
     }
 
     #[test]
-    fn vector2_should_implement_distance() {
-        test_distance_procedure(Vector2::new(0., 0.), Vector2::new(1., 0.), 1.);
-        test_distance_procedure(Vector2::new(2., -4.), Vector2::new(-1., 0.), 5.);
+    fn point2_should_implement_distance() {
+        test_distance_procedure(Point2::new(0., 0.), Point2::new(1., 0.), 1.);
+        test_distance_procedure(Point2::new(2., -4.), Point2::new(-1., 0.), 5.);
     }
     
-    fn test_direction_procedure(vec1: Vector2, vec2: Vector2, expected_direction: Vector2) {
-        assert_eq!(expected_direction, vec1.direction(&vec2));
+    fn test_direction_procedure(point1: Point2, point2: Point2, expected_direction: Vector2) {
+        assert_eq!(expected_direction, point1.direction(&point2).unwrap().to_vector());
         let neg_direction = Vector2::new(-expected_direction.x(), -expected_direction.y());
-        assert_eq!(neg_direction, vec2.direction(&vec1));
+        assert_eq!(neg_direction, point2.direction(&point1).unwrap().to_vector());
     }
 
     #[test]
-    fn vector2_should_implement_direction() {
+    fn point2_should_implement_direction() {
         test_direction_procedure(
-            Vector2::new(10., 15.),
-            Vector2::new(10., 25.),
+            Point2::new(10., 15.),
+            Point2::new(10., 25.),
             Vector2::new(0., 1.)
         );
 
         
         let expected_component = 1./2_f32.sqrt();
         test_direction_procedure(
-            Vector2::new(1., 0.),
-            Vector2::new(3., 2.),
+            Point2::new(1., 0.),
+            Point2::new(3., 2.),
             Vector2::new(expected_component, expected_component)
         );
+
+        assert_eq!(None, Point2::new(1., 2.).direction(&Point2::new(1., 2.)));
+    }
+
+    #[test]
+    fn point2_should_implement_lerp() {
+        let point1 = Point2::new(0., 2.);
+        let point2 = Point2::new(4., -2.);
+        assert_eq!(point1, point1.lerp(&point2, 0.));
+        assert_eq!(Point2::new(2., 0.), point1.lerp(&point2, 0.5));
+        assert_eq!(point2, point1.lerp(&point2, 1.));
     }
 
     #[test]
@@ -110,6 +325,9 @@ mod tests {
 
         let vec2 = Vector2::new(-1., 0.);
         assert_eq!(PI, vec1.wide_angle(&vec2));
+
+        assert_eq!(0., Vector2::new(1e10, 0.).wide_angle(&Vector2::new(1e10, 0.)));
+        assert_eq!(3.*PI/2., Vector2::new(1e-12, 0.).wide_angle(&Vector2::new(0., -1e-12)));
     }
 
     #[test]
@@ -167,4 +385,194 @@ mod tests {
         let vec1 = Vector2::new(-1.5, 2.);
         assert_eq!(Vector2::new(-2., 2.), vec1.floor());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn vector2_should_implement_rounding(){
+        let vec1 = Vector2::new(7.5, -5.5);
+        assert_eq!(Vector2::new(8., -5.), vec1.ceil());
+        assert_eq!(Vector2::new(8., -6.), vec1.round());
+        assert_eq!(Vector2::new(7., -5.), vec1.trunc());
+    }
+
+    #[test]
+    fn vector2_should_implement_sqrt_and_recip(){
+        let vec1 = Vector2::new(16., 0.25);
+        assert_eq!(Vector2::new(4., 0.5), vec1.sqrt());
+        assert_eq!(Vector2::new(0.0625, 4.), vec1.recip());
+    }
+
+    #[test]
+    fn vector2_should_implement_signum_and_copysign(){
+        let vec1 = Vector2::new(-3., 0.);
+        assert_eq!(Vector2::new(-1., 1.), vec1.signum());
+        assert_eq!(Vector2::new(3., -0.), vec1.copysign(&Vector2::new(1., -2.)));
+    }
+
+    #[test]
+    fn vector2_should_implement_powf_and_exp(){
+        let vec1 = Vector2::new(2., 9.);
+        assert_eq!(Vector2::new(4., 81.), vec1.powf(2.));
+        let vec1 = Vector2::new(0., 1.).exp();
+        assert_eq!(1., vec1.x());
+        test_tools::assert_approx_eq!(std::f32::consts::E, &vec1.y());
+    }
+
+    #[test]
+    fn vector2_should_implement_mul_add(){
+        let vec1 = Vector2::new(2., 3.);
+        assert_eq!(Vector2::new(7., -5.), vec1.mul_add(&Vector2::new(3., -2.), &Vector2::new(1., 1.)));
+    }
+
+    #[test]
+    fn vector2_should_implement_min_max(){
+        let vec1 = Vector2::new(1., 5.);
+        let vec2 = Vector2::new(3., -2.);
+        assert_eq!(Vector2::new(1., -2.), vec1.min(&vec2));
+        assert_eq!(Vector2::new(3., 5.), vec1.max(&vec2));
+        assert_eq!(1., vec1.min_element());
+        assert_eq!(5., vec1.max_element());
+        assert_eq!(-2, Vector2::<i32>::new(7, -2).min_element());
+    }
+
+    #[test]
+    #[cfg(not(feature = "validate"))]
+    fn vector2_min_max_should_ignore_nan(){
+        let vec1 = Vector2::new(f32::NAN, 2.);
+        let vec2 = Vector2::new(1., f32::NAN);
+        assert_eq!(Vector2::new(1., 2.), vec1.min(&vec2));
+        assert_eq!(Vector2::new(1., 2.), vec2.max(&vec1));
+        assert_eq!(2., vec1.max_element());
+        assert!(vec1.clamp(&Vector2::new(0., 0.), &Vector2::new(1., 1.)).x().is_nan());
+    }
+
+    #[test]
+    fn vector2_should_implement_clamp(){
+        let lower = Vector2::new(0., 0.);
+        let upper = Vector2::new(10., 5.);
+        assert_eq!(Vector2::new(0., 5.), Vector2::new(-3., 8.).clamp(&lower, &upper));
+        assert_eq!(Vector2::new(4., 2.), Vector2::new(4., 2.).clamp(&lower, &upper));
+    }
+
+    #[test]
+    fn vector2_should_implement_map_and_zip_with(){
+        let vec1 = Vector2::new(1.5, -2.);
+        assert_eq!(Vector2::new(3., -4.), vec1.map(|c| c * 2.));
+        assert_eq!(Vector2::<i32>::new(1, -2), vec1.map(|c| c as i32));
+
+        let vec2 = Vector2::<i32>::new(2, 3);
+        assert_eq!(Vector2::new(3., -6.), vec1.zip_with(&vec2, |a, b| a * b as f32));
+    }
+
+    #[test]
+    fn vector2_should_implement_slerp() {
+        let vec1 = Vector2::new(2., 0.);
+        let vec2 = Vector2::new(0., 2.);
+        assert_eq!(vec1, vec1.slerp(&vec2, 0.));
+        crate::assert_vec_approx_eq!(vec2, vec1.slerp(&vec2, 1.));
+
+        let middle = vec1.slerp(&vec2, 0.5);
+        test_tools::assert_approx_eq!(2., &middle.magnitude());
+        test_tools::assert_approx_eq!(PI/4., &middle.to_angle());
+
+        let vec2 = Vector2::new(0., -2.);
+        test_tools::assert_approx_eq!(-PI/4., &vec1.slerp(&vec2, 0.5).to_angle());
+        assert_eq!(vec1, vec1.slerp(&Vector2::new(0., 0.), 0.5));
+        assert_eq!(Vector2::new(0., 0.), Vector2::new(0., 0.).slerp(&vec2, 0.5));
+    }
+
+    #[test]
+    fn vector2_slerp_should_keep_the_magnitude_of_self() {
+        let vec1 = Vector2::new(2., 0.);
+        let vec2 = Vector2::new(0., 8.);
+        let middle = vec1.slerp(&vec2, 0.5);
+        test_tools::assert_approx_eq!(2., &middle.magnitude());
+        test_tools::assert_approx_eq!(PI/4., &middle.to_angle());
+        crate::assert_vec_approx_eq!(Vector2::new(0., 2.), vec1.slerp(&vec2, 1.));
+
+        let vec1 = Vector2::new(1e10, 0.);
+        crate::assert_vec_approx_eq!(Vector2::new(0., 1e10), vec1.slerp(&Vector2::new(0., 1e-12), 1.), rel = 1e-6);
+    }
+
+    #[test]
+    fn vector2_should_implement_nlerp() {
+        let vec1 = Vector2::new(2., 0.);
+        let vec2 = Vector2::new(0., 2.);
+        let expected_xy = 1./2_f32.sqrt();
+        assert_eq!(Vector2::new(expected_xy, expected_xy), vec1.nlerp(&vec2, 0.5));
+        assert_eq!(Vector2::new(0., 0.), vec1.nlerp(&-vec1, 0.5));
+    }
+
+    #[test]
+    fn vector2_should_implement_move_towards() {
+        let vec1 = Vector2::new(0., 0.);
+        let target = Vector2::new(3., 4.);
+        crate::assert_vec_approx_eq!(Vector2::new(0.6, 0.8), vec1.move_towards(&target, 1.));
+        assert_eq!(target, vec1.move_towards(&target, 5.));
+        assert_eq!(target, vec1.move_towards(&target, 10.));
+        assert_eq!(target, target.move_towards(&target, 1.));
+    }
+
+    #[test]
+    fn vector2_should_implement_smooth_damp() {
+        let target = Vector2::new(10., -5.);
+        let mut position = Vector2::new(0., 0.);
+        let mut velocity = Vector2::new(0., 0.);
+
+        let first_step = position.smooth_damp(&target, &mut velocity, 0.5, 1./60.);
+        assert!(first_step.x() > 0. && first_step.x() < 10.);
+        assert!(velocity.x() > 0. && velocity.y() < 0.);
+
+        for _ in 0..600 {
+            position = position.smooth_damp(&target, &mut velocity, 0.5, 1./60.);
+            assert!(position.x() <= target.x());
+        }
+        crate::assert_vec_approx_eq!(target, position, abs = 1e-3);
+        crate::assert_vec_approx_eq!(Vector2::new(0., 0.), velocity, abs = 1e-3);
+    }
+
+    #[test]
+    fn vector2_should_implement_clamp_magnitude() {
+        let vec1 = Vector2::new(3., 4.);
+        crate::assert_vec_approx_eq!(Vector2::new(0.6, 0.8), vec1.clamp_magnitude(0., 1.));
+        assert_eq!(Vector2::new(6., 8.), vec1.clamp_magnitude(10., 20.));
+        assert_eq!(vec1, vec1.clamp_magnitude(1., 10.));
+        assert_eq!(Vector2::new(0., 0.), Vector2::new(0., 0.).clamp_magnitude(1., 10.));
+    }
+
+    #[test]
+    fn vector2_should_implement_with_magnitude() {
+        assert_eq!(Vector2::new(6., 8.), Vector2::new(3., 4.).with_magnitude(10.));
+        assert_eq!(Vector2::new(0., 0.), Vector2::new(0., 0.).with_magnitude(10.));
+    }
+
+    #[test]
+    fn vector3_should_implement_distance() {
+        let vec1 = Vector3::new(1., 1., 1.);
+        let vec2 = Vector3::new(3., 4., 7.);
+        assert_eq!(7., vec1.distance(&vec2));
+        assert_eq!(7., vec2.distance(&vec1));
+    }
+
+    #[test]
+    fn vector3_should_implement_lerp() {
+        let vec1 = Vector3::new(0., 2., -4.);
+        let vec2 = Vector3::new(4., 2., 4.);
+        assert_eq!(vec1, vec1.lerp(&vec2, 0.));
+        assert_eq!(Vector3::new(2., 2., 0.), vec1.lerp(&vec2, 0.5));
+        assert_eq!(vec2, vec1.lerp(&vec2, 1.));
+    }
+
+    #[test]
+    fn vector4_should_implement_distance() {
+        let vec1 = Vector4::new(1., 1., 1., 1.);
+        let vec2 = Vector4::new(2., 2., 2., 2.);
+        assert_eq!(2., vec1.distance(&vec2));
+    }
+
+    #[test]
+    fn vector4_should_implement_lerp() {
+        let vec1 = Vector4::new(0., 2., -4., 1.);
+        let vec2 = Vector4::new(4., 2., 4., 3.);
+        assert_eq!(Vector4::new(1., 2., -2., 1.5), vec1.lerp(&vec2, 0.25));
+    }
+}
@@ -0,0 +1,278 @@
+use crate::point2::Point2;
+use crate::scalar::Real;
+use crate::segment::{impl_shape_traits, line_crossing, LineCrossing, Segment2};
+use crate::shapes::{Aabb, Circle};
+use crate::space::UnknownSpace;
+use crate::unit_vector2::UnitVector2;
+use crate::vector2::Vector2;
+
+/// Half line from `origin` along `direction`.
+pub struct Ray2<T = f32, S = UnknownSpace> {
+    pub origin: Point2<T, S>,
+    pub direction: UnitVector2<T, S>,
+}
+
+/// First contact of a ray, `normal` facing back against the ray.
+/// A ray starting inside a solid shape hits at distance 0, the normal against its direction.
+pub struct RayHit<T = f32, S = UnknownSpace> {
+    pub distance: T,
+    pub point: Point2<T, S>,
+    pub normal: UnitVector2<T, S>,
+}
+
+impl_shape_traits!(Ray2 { origin, direction });
+impl_shape_traits!(RayHit { distance, point, normal });
+
+impl<T: Real, S> Ray2<T, S> {
+    pub fn new(origin: Point2<T, S>, direction: UnitVector2<T, S>) -> Self {
+        Ray2{origin, direction}
+    }
+
+    /// `None` for a zero length `direction`.
+    pub fn from_vector(origin: Point2<T, S>, direction: &Vector2<T, S>) -> Option<Self> {
+        direction.try_normalize().map(|direction| Ray2::new(origin, direction))
+    }
+
+    /// `None` when `target` is the origin.
+    pub fn towards(origin: Point2<T, S>, target: &Point2<T, S>) -> Option<Self> {
+        origin.direction(target).map(|direction| Ray2::new(origin, direction))
+    }
+
+    pub fn point_at(&self, distance: T) -> Point2<T, S> {
+        self.origin + self.direction * distance
+    }
+
+    fn hit(&self, distance: T, normal: UnitVector2<T, S>) -> RayHit<T, S> {
+        RayHit{distance, point: self.point_at(distance), normal}
+    }
+
+    fn inside_hit(&self) -> RayHit<T, S> {
+        self.hit(T::ZERO, -self.direction)
+    }
+
+    /// Collinear segments are hit at their nearest point ahead of the origin,
+    /// with the same parallel tolerance as `Segment2::intersection`.
+    pub fn cast_segment(&self, segment: &Segment2<T, S>) -> Option<RayHit<T, S>> {
+        let direction = self.direction.to_vector();
+        let (distance, u) = match line_crossing(&self.origin, &direction, segment) {
+            LineCrossing::Parallel => return None,
+            LineCrossing::Collinear(t_a, t_b) => {
+                let (near, far) = if t_a < t_b { (t_a, t_b) } else { (t_b, t_a) };
+                if far < T::ZERO { return None; }
+                let distance = if near > T::ZERO { near } else { T::ZERO };
+                return Some(self.hit(distance, -self.direction));
+            }
+            LineCrossing::Crossing(distance, u) => (distance, u),
+        };
+        if distance < T::ZERO || u < T::ZERO || u > T::ONE { return None; }
+
+        let normal = segment.vector().perp_ccw().try_normalize()?;
+        let normal = if normal.as_vector().dot(&direction) > T::ZERO { -normal } else { normal };
+        Some(self.hit(distance, normal))
+    }
+
+    pub fn cast_circle(&self, circle: &Circle<T, S>) -> Option<RayHit<T, S>> {
+        let offset = self.origin - circle.center;
+        let b = offset.dot(self.direction.as_vector());
+        let c = offset.magnitude_squared() - circle.radius * circle.radius;
+        if c <= T::ZERO { return Some(self.inside_hit()); }
+        if b > T::ZERO { return None; }
+
+        let discriminant = b * b - c;
+        if discriminant < T::ZERO { return None; }
+
+        let distance = -b - discriminant.sqrt();
+        let point = self.point_at(distance);
+        let normal = (point - circle.center).try_normalize().unwrap_or(-self.direction);
+        Some(RayHit{distance, point, normal})
+    }
+
+    /// Slab test, the normal is the face crossed on entry.
+    pub fn cast_aabb(&self, aabb: &Aabb<T, S>) -> Option<RayHit<T, S>> {
+        let (origin, direction) = (self.origin.to_vector(), self.direction.to_vector());
+        let (min, max) = (aabb.min.to_vector(), aabb.max.to_vector());
+
+        // Unbounded until an axis not parallel to the ray bounds them.
+        let (mut enter, mut exit): (Option<T>, Option<T>) = (None, None);
+        let mut enter_normal = -self.direction;
+
+        for axis in 0..2 {
+            if direction[axis] == T::ZERO {
+                if origin[axis] < min[axis] || origin[axis] > max[axis] { return None; }
+                continue;
+            }
+
+            let t1 = (min[axis] - origin[axis]) / direction[axis];
+            let t2 = (max[axis] - origin[axis]) / direction[axis];
+            let (near, far) = if t1 < t2 { (t1, t2) } else { (t2, t1) };
+
+            if enter.is_none_or(|enter| near > enter) {
+                enter = Some(near);
+                let mut normal = Vector2::in_space(T::ZERO, T::ZERO);
+                normal[axis] = if direction[axis] > T::ZERO { -T::ONE } else { T::ONE };
+                enter_normal = UnitVector2::new_unchecked(normal);
+            }
+            if exit.is_none_or(|exit| far < exit) { exit = Some(far); }
+        }
+
+        // A unit direction has a non zero axis, both bounds are set.
+        let (enter, exit) = (enter?, exit?);
+        if exit < enter || exit < T::ZERO { return None; }
+        if enter <= T::ZERO { return Some(self.inside_hit()); }
+        Some(self.hit(enter, enter_normal))
+    }
+
+    /// `vertices` form a closed polygon, the last one joining the first one.
+    /// The inside follows the even-odd rule.
+    pub fn cast_polygon(&self, vertices: &[Point2<T, S>]) -> Option<RayHit<T, S>> {
+        if vertices.is_empty() { return None; }
+        if contains_point(vertices, &self.origin) { return Some(self.inside_hit()); }
+
+        let edges = vertices.iter().zip(vertices.iter().cycle().skip(1));
+        edges
+            .filter_map(|(a, b)| self.cast_segment(&Segment2::new(*a, *b)))
+            .fold(None, |nearest: Option<RayHit<T, S>>, hit| match nearest {
+                Some(nearest) if nearest.distance <= hit.distance => Some(nearest),
+                _ => Some(hit),
+            })
+    }
+}
+
+/// Even-odd rule, counting the edges crossed by a horizontal half line.
+fn contains_point<T: Real, S>(vertices: &[Point2<T, S>], point: &Point2<T, S>) -> bool {
+    let mut inside = false;
+    let edges = vertices.iter().zip(vertices.iter().cycle().skip(1));
+    for (a, b) in edges {
+        if (a.y() > point.y()) != (b.y() > point.y()) {
+            let crossing_x = a.x() + (point.y() - a.y()) * (b.x() - a.x()) / (b.y() - a.y());
+            if point.x() < crossing_x { inside = !inside; }
+        }
+    }
+    inside
+}
+
+#[cfg(test)]
+mod tests {
+    //  _______________
+    //
+    //  TESTS OVER RAYS
+    //  _______________
+    //  Ray2 Implements :
+    //  - new, from_vector, towards
+    //  - point_at      (Self, f32) -> Point2
+    //  - cast_segment  (Self, Segment2) -> Option<RayHit>
+    //  - cast_circle   (Self, Circle) -> Option<RayHit>
+    //  - cast_aabb     (Self, Aabb) -> Option<RayHit>
+    //  - cast_polygon  (Self, &[Point2]) -> Option<RayHit>
+    //  RayHit carries distance, point and normal
+
+    use super::Ray2;
+    use crate::point2::Point2;
+    use crate::segment::Segment2;
+    use crate::shapes::{Aabb, Circle};
+    use crate::unit_vector2::UnitVector2;
+    use crate::vector2::Vector2;
+    use crate::test_tools;
+
+    #[test]
+    fn ray2_should_implement_construction() {
+        assert_eq!(None, Ray2::from_vector(Point2::new(1., 1.), &Vector2::new(0., 0.)));
+        assert_eq!(None, Ray2::towards(Point2::new(1., 1.), &Point2::new(1., 1.)));
+
+        let ray1 = Ray2::towards(Point2::new(1., 1.), &Point2::new(1., 5.)).unwrap();
+        assert_eq!(UnitVector2::y_axis(), ray1.direction);
+        assert_eq!(Point2::new(1., 3.), ray1.point_at(2.));
+        assert_eq!(ray1, Ray2::from_vector(Point2::new(1., 1.), &Vector2::new(0., 10.)).unwrap());
+    }
+
+    #[test]
+    fn ray2_should_cast_segment() {
+        let wall = Segment2::new(Point2::new(5., -1.), Point2::new(5., 1.));
+        let hit = Ray2::new(Point2::new(0., 0.), UnitVector2::x_axis()).cast_segment(&wall).unwrap();
+        assert_eq!(5., hit.distance);
+        assert_eq!(Point2::new(5., 0.), hit.point);
+        assert_eq!(-UnitVector2::x_axis(), hit.normal);
+
+        let hit = Ray2::new(Point2::new(10., 0.), -UnitVector2::x_axis()).cast_segment(&wall).unwrap();
+        assert_eq!(UnitVector2::x_axis(), hit.normal);
+
+        assert_eq!(None, Ray2::new(Point2::new(0., 0.), -UnitVector2::x_axis()).cast_segment(&wall));
+        assert_eq!(None, Ray2::new(Point2::new(0., 2.), UnitVector2::x_axis()).cast_segment(&wall));
+    }
+
+    #[test]
+    fn ray2_should_cast_collinear_segment() {
+        let segment1 = Segment2::new(Point2::new(3., 0.), Point2::new(6., 0.));
+        assert_eq!(3., Ray2::new(Point2::new(0., 0.), UnitVector2::x_axis()).cast_segment(&segment1).unwrap().distance);
+        assert_eq!(0., Ray2::new(Point2::new(4., 0.), UnitVector2::x_axis()).cast_segment(&segment1).unwrap().distance);
+        assert_eq!(None, Ray2::new(Point2::new(7., 0.), UnitVector2::x_axis()).cast_segment(&segment1));
+    }
+
+    #[test]
+    fn ray2_should_cast_nearly_collinear_segment() {
+        // Along the segment, but rounding leaves a non zero determinent.
+        let ray1 = Ray2::from_vector(Point2::new(0.1, 0.3), &Vector2::new(0.1, 0.3)).unwrap();
+        let hit = ray1.cast_segment(&Segment2::new(Point2::new(0.2, 0.6), Point2::new(0.5, 1.5))).unwrap();
+        test_tools::assert_approx_eq!(0.1f32.hypot(0.3), &hit.distance);
+        crate::assert_vec_approx_eq!(Point2::new(0.2, 0.6), hit.point);
+        assert_eq!(-ray1.direction, hit.normal);
+    }
+
+    #[test]
+    fn ray2_should_cast_circle() {
+        let circle = Circle::new(Point2::new(10., 0.), 2.);
+        let hit = Ray2::new(Point2::new(0., 0.), UnitVector2::x_axis()).cast_circle(&circle).unwrap();
+        assert_eq!(8., hit.distance);
+        assert_eq!(Point2::new(8., 0.), hit.point);
+        assert_eq!(-UnitVector2::x_axis(), hit.normal);
+
+        let hit = Ray2::new(Point2::new(10., 10.), -UnitVector2::y_axis()).cast_circle(&circle).unwrap();
+        assert_eq!(8., hit.distance);
+        assert_eq!(UnitVector2::y_axis(), hit.normal);
+
+        assert_eq!(None, Ray2::new(Point2::new(0., 0.), -UnitVector2::x_axis()).cast_circle(&circle));
+        assert_eq!(None, Ray2::new(Point2::new(0., 3.), UnitVector2::x_axis()).cast_circle(&circle));
+
+        let inside = Ray2::new(Point2::new(10., 1.), UnitVector2::x_axis()).cast_circle(&circle).unwrap();
+        assert_eq!(0., inside.distance);
+        assert_eq!(-UnitVector2::x_axis(), inside.normal);
+    }
+
+    #[test]
+    fn ray2_should_cast_aabb() {
+        let aabb = Aabb::new(Point2::new(2., -1.), Point2::new(4., 1.));
+        let hit = Ray2::new(Point2::new(0., 0.), UnitVector2::x_axis()).cast_aabb(&aabb).unwrap();
+        assert_eq!(2., hit.distance);
+        assert_eq!(-UnitVector2::x_axis(), hit.normal);
+
+        let hit = Ray2::new(Point2::new(3., 5.), -UnitVector2::y_axis()).cast_aabb(&aabb).unwrap();
+        assert_eq!(4., hit.distance);
+        assert_eq!(Point2::new(3., 1.), hit.point);
+        assert_eq!(UnitVector2::y_axis(), hit.normal);
+
+        let hit = Ray2::from_vector(Point2::new(0., -2.), &Vector2::new(1., 1.)).unwrap().cast_aabb(&aabb).unwrap();
+        test_tools::assert_approx_eq!(2. * 2_f32.sqrt(), &hit.distance);
+        assert_eq!(-UnitVector2::x_axis(), hit.normal);
+
+        assert_eq!(None, Ray2::new(Point2::new(0., 2.), UnitVector2::x_axis()).cast_aabb(&aabb));
+        assert_eq!(None, Ray2::new(Point2::new(5., 0.), UnitVector2::x_axis()).cast_aabb(&aabb));
+        assert_eq!(0., Ray2::new(Point2::new(3., 0.), UnitVector2::x_axis()).cast_aabb(&aabb).unwrap().distance);
+    }
+
+    #[test]
+    fn ray2_should_cast_polygon() {
+        let triangle = [Point2::new(2., -2.), Point2::new(6., 0.), Point2::new(2., 2.)];
+        let hit = Ray2::new(Point2::new(0., 0.), UnitVector2::x_axis()).cast_polygon(&triangle).unwrap();
+        assert_eq!(2., hit.distance);
+        assert_eq!(-UnitVector2::x_axis(), hit.normal);
+
+        let hit = Ray2::new(Point2::new(10., 0.5), -UnitVector2::x_axis()).cast_polygon(&triangle).unwrap();
+        assert_eq!(5., hit.distance);
+        test_tools::assert_approx_eq!(0., &hit.normal.as_vector().dot(&Vector2::new(-4., 2.)));
+        assert!(hit.normal.x() > 0.);
+
+        assert_eq!(None, Ray2::new(Point2::new(0., 5.), UnitVector2::x_axis()).cast_polygon(&triangle));
+        assert_eq!(0., Ray2::new(Point2::new(3., 0.), UnitVector2::x_axis()).cast_polygon(&triangle).unwrap().distance);
+        assert_eq!(None, Ray2::new(Point2::new(0., 0.), UnitVector2::x_axis()).cast_polygon(&[]));
+    }
+}
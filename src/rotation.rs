@@ -0,0 +1,192 @@
+pub mod rotations {
+    use crate::vector2::Vector2;
+    use crate::point2::Point2;
+    use crate::unit_vector2::UnitVector2;
+    use crate::scalar::{Real, Scalar};
+    use std::ops::Neg;
+
+    /// `(sin, cos)`, exact when `radians` is a whole number of quarter turns,
+    /// so rotating grid-aligned vectors keeps them grid-aligned.
+    /// Beyond a million quarter turns every float is a whole number, nothing is snapped there.
+    pub(crate) fn sin_cos<T: Real>(radians: T) -> (T, T) {
+        let two = T::ONE + T::ONE;
+        let quarters = radians / (T::PI / two);
+        if quarters != quarters.round() || quarters.abs() >= T::from_f64(1e6) {
+            return (radians.sin(), radians.cos());
+        }
+
+        let quarters = quarters.rem_euclid(two + two);
+        if quarters == T::ZERO { (T::ZERO, T::ONE) }
+        else if quarters == T::ONE { (T::ONE, T::ZERO) }
+        else if quarters == two { (T::ZERO, -T::ONE) }
+        else { (-T::ONE, T::ZERO) }
+    }
+
+    impl<T: Scalar + Neg<Output = T>, S> Vector2<T, S> {
+        /// Quarter turn counter-clockwise.
+        pub fn perp_ccw(&self) -> Self {
+            Vector2::in_space(-self.y(), self.x())
+        }
+
+        /// Quarter turn clockwise.
+        pub fn perp_cw(&self) -> Self {
+            Vector2::in_space(self.y(), -self.x())
+        }
+    }
+
+    impl<T: Real, S> Vector2<T, S> {
+        /// Unit vector at `radians` counter-clockwise from the x axis.
+        pub fn from_angle(radians: T) -> Self {
+            UnitVector2::from_angle(radians).to_vector()
+        }
+    }
+
+    impl<T: Real, S> UnitVector2<T, S> {
+        /// At `radians` counter-clockwise from the x axis, unit length by construction.
+        pub fn from_angle(radians: T) -> Self {
+            let (sin, cos) = sin_cos(radians);
+            UnitVector2::new_unchecked(Vector2::in_space(cos, sin))
+        }
+
+        /// Counter-clockwise rotation, which keeps the unit length.
+        pub fn rotated(&self, radians: T) -> Self {
+            UnitVector2::new_unchecked(self.to_vector().rotated(radians))
+        }
+    }
+
+    impl<T: Real, S> Vector2<T, S> {
+        /// Counter-clockwise rotation.
+        pub fn rotated(&self, radians: T) -> Self {
+            let (sin, cos) = sin_cos(radians);
+            Vector2::in_space(
+                self.x() * cos - self.y() * sin,
+                self.x() * sin + self.y() * cos
+            )
+        }
+
+        pub fn rotated_around(&self, pivot: &Self, radians: T) -> Self {
+            (*self - *pivot).rotated(radians) + *pivot
+        }
+    }
+
+    impl<T: Real, S> Point2<T, S> {
+        pub fn rotated_around(&self, pivot: &Self, radians: T) -> Self {
+            *pivot + (*self - *pivot).rotated(radians)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    //  _________________________
+    //
+    //  TESTS OVER ROTATION TOOLS
+    //  _________________________
+    //  Vector2 Implements :
+    //  - perp_ccw, perp_cw (Self) -> Self
+    //  - from_angle        (f32) -> Self
+    //  - rotated           (Self, f32) -> Self
+    //  - rotated_around    (Self, Self, f32) -> Self
+    //  Point2 Implements :
+    //  - rotated_around    (Self, Self, f32) -> Self
+    //  UnitVector2 Implements :
+    //  - from_angle        (f32) -> Self
+    //  - rotated           (Self, f32) -> Self
+
+    use crate::vector2::Vector2;
+    use crate::point2::Point2;
+    use crate::unit_vector2::UnitVector2;
+    use crate::fixed::Fixed;
+    use crate::scalar::Real;
+    use std::f32::consts::{FRAC_PI_2, PI};
+    use crate::test_tools;
+
+    #[test]
+    fn vector2_should_implement_perp() {
+        let vec1 = Vector2::new(3., 1.);
+        assert_eq!(Vector2::new(-1., 3.), vec1.perp_ccw());
+        assert_eq!(Vector2::new(1., -3.), vec1.perp_cw());
+        assert_eq!(0., vec1.dot(&vec1.perp_ccw()));
+        assert_eq!(Vector2::<i32>::new(-1, 3), Vector2::<i32>::new(3, 1).perp_ccw());
+    }
+
+    #[test]
+    fn vector2_should_implement_from_angle() {
+        assert_eq!(Vector2::new(1., 0.), Vector2::from_angle(0.));
+        assert_eq!(Vector2::new(0., 1.), Vector2::from_angle(PI/2.));
+        assert_eq!(Vector2::new(-1., 0.), Vector2::from_angle(PI));
+        assert_eq!(Vector2::new(0., -1.), Vector2::from_angle(-PI/2.));
+
+        let vec1: Vector2 = Vector2::from_angle(PI/3.);
+        test_tools::assert_approx_eq!(0.5, &vec1.x());
+        test_tools::assert_approx_eq!(PI/3., &vec1.to_angle());
+    }
+
+    #[test]
+    fn unit_vector2_should_implement_from_angle_and_rotated() {
+        let unit1: UnitVector2 = UnitVector2::from_angle(PI/2.);
+        assert_eq!(UnitVector2::y_axis(), unit1);
+        assert_eq!(UnitVector2::new_unchecked(Vector2::new(-1., 0.)), unit1.rotated(PI/2.));
+
+        let unit1: UnitVector2 = UnitVector2::from_angle(0.4).rotated(1.3);
+        test_tools::assert_approx_eq!(1., &unit1.as_vector().magnitude());
+        test_tools::assert_approx_eq!(1.7, &unit1.to_vector().to_angle());
+    }
+
+    #[test]
+    fn vector2_from_angle_should_not_snap_large_angles() {
+        let radians = 1e8_f32;
+        assert_eq!(Vector2::new(radians.cos(), radians.sin()), Vector2::from_angle(radians));
+        assert_eq!(Vector2::new(radians.cos(), radians.sin()), Vector2::new(1., 0.).rotated(radians));
+    }
+
+    #[test]
+    fn vector2_rotated_should_be_exact_for_quarter_turns() {
+        let vec1 = Vector2::new(3., -2.);
+        assert_eq!(vec1.perp_ccw(), vec1.rotated(PI/2.));
+        assert_eq!(vec1.perp_ccw(), vec1.rotated(FRAC_PI_2));
+        assert_eq!(-vec1, vec1.rotated(PI));
+        assert_eq!(vec1.perp_cw(), vec1.rotated(3.*PI/2.));
+        assert_eq!(vec1.perp_cw(), vec1.rotated(-PI/2.));
+        assert_eq!(vec1, vec1.rotated(2.*PI));
+        assert_eq!(vec1, vec1.rotated(0.));
+
+        let vec1 = Vector2::new(3_f64, -2.);
+        assert_eq!(vec1.perp_ccw(), vec1.rotated(5.*std::f64::consts::PI/2.));
+    }
+
+    #[test]
+    fn vector2_should_implement_rotated() {
+        let vec1 = Vector2::new(2., 0.).rotated(PI/4.);
+        test_tools::assert_approx_eq!(2_f32.sqrt(), &vec1.x());
+        test_tools::assert_approx_eq!(2_f32.sqrt(), &vec1.y());
+
+        let vec1 = Vector2::new(1., 2.);
+        crate::assert_vec_approx_eq!(vec1, vec1.rotated(0.7).rotated(-0.7));
+        test_tools::assert_approx_eq!(vec1.magnitude(), &vec1.rotated(1.3).magnitude());
+    }
+
+    #[test]
+    fn fixed_vector2_rotated_should_be_exact_for_quarter_turns() {
+        let vec1 = Vector2::new(Fixed::from_int(3), Fixed::from_int(-2));
+        let half_pi = Fixed::PI / Fixed::from_int(2);
+        assert_eq!(vec1.perp_ccw(), vec1.rotated(half_pi));
+        assert_eq!(-vec1, vec1.rotated(Fixed::PI));
+    }
+
+    #[test]
+    fn vector2_should_implement_rotated_around() {
+        let vec1 = Vector2::new(3., 1.);
+        let pivot = Vector2::new(1., 1.);
+        assert_eq!(Vector2::new(1., 3.), vec1.rotated_around(&pivot, PI/2.));
+        assert_eq!(Vector2::new(-1., 1.), vec1.rotated_around(&pivot, PI));
+        assert_eq!(pivot, pivot.rotated_around(&pivot, 1.2));
+    }
+
+    #[test]
+    fn point2_should_implement_rotated_around() {
+        let point1 = Point2::new(3., 1.);
+        let pivot = Point2::new(1., 1.);
+        assert_eq!(Point2::new(1., -1.), point1.rotated_around(&pivot, -PI/2.));
+    }
+}
@@ -0,0 +1,242 @@
+use std::cmp::Ordering;
+use std::fmt::Debug;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign};
+
+pub trait Scalar:
+    Copy
+    + Debug
+    + PartialEq
+    + PartialOrd
+    + Add<Output = Self>
+    + AddAssign
+    + Sub<Output = Self>
+    + SubAssign
+    + Mul<Output = Self>
+    + MulAssign
+    + Div<Output = Self>
+    + DivAssign
+    + Rem<Output = Self>
+    + RemAssign
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    /// Only floating point scalars can hold NaN or infinities.
+    fn is_finite(self) -> bool {
+        true
+    }
+
+    fn is_nan(self) -> bool {
+        false
+    }
+
+    /// `self / other`, `None` where the quotient cannot be represented.
+    /// Floats represent every quotient, infinities and NaN included.
+    fn checked_quotient(self, other: Self) -> Option<Self> {
+        Some(self / other)
+    }
+
+    /// `self % other`, `None` where the remainder cannot be represented.
+    fn checked_remainder(self, other: Self) -> Option<Self> {
+        Some(self % other)
+    }
+
+    /// `x1 * x2 + y1 * y2`, the 2D dot product.
+    /// Narrow scalars override it to carry the products wider than `Self`.
+    fn dot2(x1: Self, y1: Self, x2: Self, y2: Self) -> Self {
+        x1 * x2 + y1 * y2
+    }
+
+    /// `x1 * y2 - y1 * x2`, the 2D determinent, carried like `dot2`.
+    fn det2(x1: Self, y1: Self, x2: Self, y2: Self) -> Self {
+        x1 * y2 - y1 * x2
+    }
+}
+
+/// Scalars able to carry the geometry : square roots, trigonometry and rounding.
+pub trait Real: Scalar + Neg<Output = Self> {
+    const PI: Self;
+
+    fn sqrt(self) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn acos(self) -> Self;
+    fn atan2(self, other: Self) -> Self;
+    fn abs(self) -> Self;
+    fn fract(self) -> Self;
+    fn floor(self) -> Self;
+    fn ceil(self) -> Self;
+    fn round(self) -> Self;
+    fn trunc(self) -> Self;
+    fn rem_euclid(self, n: Self) -> Self;
+
+    fn from_f64(value: f64) -> Self;
+    fn to_f64(self) -> f64;
+
+    /// Length of (x, y). Narrow scalars override it so that the squares may exceed `Self`.
+    fn length2(x: Self, y: Self) -> Self {
+        (x * x + y * y).sqrt()
+    }
+
+    /// Counter-clockwise angle from (x1, y1) to (x2, y2), in (-PI, PI].
+    /// Narrow scalars override it so that the products may exceed `Self`.
+    fn angle2(x1: Self, y1: Self, x2: Self, y2: Self) -> Self {
+        Self::det2(x1, y1, x2, y2).atan2(Self::dot2(x1, y1, x2, y2))
+    }
+}
+
+/// Hardware floating points, for the functions `Fixed` cannot carry.
+pub trait Float: Real {
+    fn powf(self, n: Self) -> Self;
+    fn exp(self) -> Self;
+    fn signum(self) -> Self;
+    fn copysign(self, sign: Self) -> Self;
+    fn mul_add(self, a: Self, b: Self) -> Self;
+}
+
+/// Grid scalars, with explicit overflow handling.
+pub trait Integer: Scalar + Ord {
+    const MIN: Self;
+    const MAX: Self;
+
+    fn checked_add(self, other: Self) -> Option<Self>;
+    fn checked_sub(self, other: Self) -> Option<Self>;
+    fn checked_mul(self, other: Self) -> Option<Self>;
+    fn checked_div(self, other: Self) -> Option<Self>;
+    fn checked_rem(self, other: Self) -> Option<Self>;
+    fn wrapping_add(self, other: Self) -> Self;
+    fn wrapping_sub(self, other: Self) -> Self;
+    fn wrapping_mul(self, other: Self) -> Self;
+    fn saturating_add(self, other: Self) -> Self;
+    fn saturating_sub(self, other: Self) -> Self;
+    fn saturating_mul(self, other: Self) -> Self;
+
+    /// Saturates out of range values to `MIN`/`MAX`, NaN becomes zero.
+    fn from_f64(value: f64) -> Self;
+    fn to_f64(self) -> f64;
+
+    /// Same width without a sign, wide enough for any `abs_diff`.
+    type Unsigned: Integer;
+
+    fn abs_diff(self, other: Self) -> Self::Unsigned;
+}
+
+/// Total order over every value of the scalar, NaN included.
+pub trait TotalOrder: Scalar {
+    /// Merges the values that should compare equal : -0.0 becomes 0.0 and every NaN the same NaN.
+    fn canonical(self) -> Self;
+    fn total_cmp(&self, other: &Self) -> Ordering;
+    /// Bits of the canonical value, equal bits for equal values.
+    fn canonical_bits(self) -> u64;
+}
+
+macro_rules! impl_real {
+    ($t: ident) => {
+        impl Scalar for $t {
+            const ZERO: Self = 0.;
+            const ONE: Self = 1.;
+
+            fn is_finite(self) -> bool { $t::is_finite(self) }
+            fn is_nan(self) -> bool { $t::is_nan(self) }
+        }
+
+        impl Real for $t {
+            const PI: Self = std::$t::consts::PI;
+
+            fn sqrt(self) -> Self { $t::sqrt(self) }
+            fn sin(self) -> Self { $t::sin(self) }
+            fn cos(self) -> Self { $t::cos(self) }
+            fn acos(self) -> Self { $t::acos(self) }
+            fn atan2(self, other: Self) -> Self { $t::atan2(self, other) }
+            fn abs(self) -> Self { $t::abs(self) }
+            fn fract(self) -> Self { $t::fract(self) }
+            fn floor(self) -> Self { $t::floor(self) }
+            fn ceil(self) -> Self { $t::ceil(self) }
+            fn round(self) -> Self { $t::round(self) }
+            fn trunc(self) -> Self { $t::trunc(self) }
+            fn rem_euclid(self, n: Self) -> Self { $t::rem_euclid(self, n) }
+
+            fn from_f64(value: f64) -> Self { value as $t }
+            fn to_f64(self) -> f64 { self as f64 }
+        }
+
+        impl Float for $t {
+            fn powf(self, n: Self) -> Self { $t::powf(self, n) }
+            fn exp(self) -> Self { $t::exp(self) }
+            fn signum(self) -> Self { $t::signum(self) }
+            fn copysign(self, sign: Self) -> Self { $t::copysign(self, sign) }
+            fn mul_add(self, a: Self, b: Self) -> Self { $t::mul_add(self, a, b) }
+        }
+
+        impl TotalOrder for $t {
+            fn canonical(self) -> Self {
+                if self.is_nan() { $t::NAN }
+                else if self == 0. { 0. }
+                else { self }
+            }
+
+            fn total_cmp(&self, other: &Self) -> Ordering {
+                $t::total_cmp(&self.canonical(), &other.canonical())
+            }
+
+            fn canonical_bits(self) -> u64 {
+                self.canonical().to_bits() as u64
+            }
+        }
+    };
+}
+
+macro_rules! impl_integer {
+    ($t: ident, $unsigned: ident) => {
+        impl Scalar for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn checked_quotient(self, other: Self) -> Option<Self> { $t::checked_div(self, other) }
+            fn checked_remainder(self, other: Self) -> Option<Self> { $t::checked_rem(self, other) }
+        }
+
+        impl Integer for $t {
+            const MIN: Self = $t::MIN;
+            const MAX: Self = $t::MAX;
+
+            type Unsigned = $unsigned;
+
+            fn checked_add(self, other: Self) -> Option<Self> { $t::checked_add(self, other) }
+            fn checked_sub(self, other: Self) -> Option<Self> { $t::checked_sub(self, other) }
+            fn checked_mul(self, other: Self) -> Option<Self> { $t::checked_mul(self, other) }
+            fn checked_div(self, other: Self) -> Option<Self> { $t::checked_div(self, other) }
+            fn checked_rem(self, other: Self) -> Option<Self> { $t::checked_rem(self, other) }
+            fn wrapping_add(self, other: Self) -> Self { $t::wrapping_add(self, other) }
+            fn wrapping_sub(self, other: Self) -> Self { $t::wrapping_sub(self, other) }
+            fn wrapping_mul(self, other: Self) -> Self { $t::wrapping_mul(self, other) }
+            fn saturating_add(self, other: Self) -> Self { $t::saturating_add(self, other) }
+            fn saturating_sub(self, other: Self) -> Self { $t::saturating_sub(self, other) }
+            fn saturating_mul(self, other: Self) -> Self { $t::saturating_mul(self, other) }
+            fn abs_diff(self, other: Self) -> $unsigned { $t::abs_diff(self, other) }
+
+            fn from_f64(value: f64) -> Self { value as $t }
+            fn to_f64(self) -> f64 { self as f64 }
+        }
+
+        impl TotalOrder for $t {
+            fn canonical(self) -> Self { self }
+            fn total_cmp(&self, other: &Self) -> Ordering { self.cmp(other) }
+            fn canonical_bits(self) -> u64 { self as u64 }
+        }
+    };
+}
+
+impl_real!(f32);
+impl_real!(f64);
+
+impl_integer!(i8, u8);
+impl_integer!(i16, u16);
+impl_integer!(i32, u32);
+impl_integer!(i64, u64);
+impl_integer!(isize, usize);
+impl_integer!(u8, u8);
+impl_integer!(u16, u16);
+impl_integer!(u32, u32);
+impl_integer!(u64, u64);
+impl_integer!(usize, usize);
@@ -0,0 +1,328 @@
+use crate::point2::Point2;
+use crate::scalar::Real;
+use crate::space::UnknownSpace;
+use crate::vector2::Vector2;
+use std::fmt::{self, Debug};
+
+//  Copy, Clone, PartialEq and Debug written by hand, field by field,
+//  so that the space marker needs none of them.
+macro_rules! impl_shape_traits {
+    ($Shape: ident { $($field: ident),+ }) => {
+        impl<T: Copy, S> Copy for $Shape<T, S> {}
+
+        impl<T: Copy, S> Clone for $Shape<T, S> {
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        impl<T: PartialEq, S> PartialEq for $Shape<T, S> {
+            fn eq(&self, other: &Self) -> bool {
+                $(self.$field == other.$field)&&+
+            }
+        }
+
+        impl<T: std::fmt::Debug, S> std::fmt::Debug for $Shape<T, S> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.debug_struct(stringify!($Shape))
+                    $(.field(stringify!($field), &self.$field))+
+                    .finish()
+            }
+        }
+    };
+}
+
+pub(crate) use impl_shape_traits;
+
+/// Line segment between the positions `a` and `b`, both included.
+pub struct Segment2<T = f32, S = UnknownSpace> {
+    pub a: Point2<T, S>,
+    pub b: Point2<T, S>,
+}
+
+/// Where two segments meet.
+pub enum SegmentIntersection<T = f32, S = UnknownSpace> {
+    None,
+    Point(Point2<T, S>),
+    /// Collinear segments sharing more than a point, the overlap runs along the first segment.
+    Overlap(Segment2<T, S>),
+}
+
+impl_shape_traits!(Segment2 { a, b });
+
+/// How a line, from `origin` along `direction`, meets the line through a segment.
+pub(crate) enum LineCrossing<T> {
+    /// Parameters of the crossing along `direction` and along the segment.
+    Crossing(T, T),
+    Parallel,
+    /// Parameters of the segment ends along `direction`.
+    Collinear(T, T),
+}
+
+/// Sine of the angle under which lines count as parallel : below it, their determinent
+/// is mostly rounding error and dividing by it would make up a crossing.
+/// Lines that close are collinear when within as small a distance, relative to the lengths.
+pub(crate) fn line_crossing<T: Real, S>(origin: &Point2<T, S>, direction: &Vector2<T, S>, segment: &Segment2<T, S>) -> LineCrossing<T> {
+    let tolerance = T::from_f64(1e-6);
+    let (edge, offset) = (segment.vector(), segment.a - *origin);
+    let (direction_length, edge_length) = (direction.magnitude(), edge.magnitude());
+    let denominator = direction.determinent(&edge);
+
+    if denominator.abs() <= tolerance * direction_length * edge_length {
+        let distance = offset.determinent(direction).abs() / direction_length;
+        if distance > tolerance * (direction_length + edge_length) { return LineCrossing::Parallel; }
+
+        let length_squared = direction.magnitude_squared();
+        return LineCrossing::Collinear(
+            offset.dot(direction) / length_squared,
+            (segment.b - *origin).dot(direction) / length_squared
+        );
+    }
+
+    LineCrossing::Crossing(offset.determinent(&edge) / denominator, offset.determinent(direction) / denominator)
+}
+
+impl<T: Real, S> Segment2<T, S> {
+    pub fn new(a: Point2<T, S>, b: Point2<T, S>) -> Self {
+        Segment2{a, b}
+    }
+
+    /// From `a` to `b`.
+    pub fn vector(&self) -> Vector2<T, S> {
+        self.b - self.a
+    }
+
+    pub fn length(&self) -> T {
+        self.vector().magnitude()
+    }
+
+    pub fn length_squared(&self) -> T {
+        self.vector().magnitude_squared()
+    }
+
+    pub fn midpoint(&self) -> Point2<T, S> {
+        self.a.lerp(&self.b, T::ONE / (T::ONE + T::ONE))
+    }
+
+    /// Position along the segment, 0 at `a` and 1 at `b`.
+    pub fn point_at(&self, t: T) -> Point2<T, S> {
+        self.a + self.vector() * t
+    }
+
+    /// Parameter of the closest point, clamped to [0, 1]. A degenerate segment gives 0.
+    pub fn closest_parameter(&self, point: &Point2<T, S>) -> T {
+        let length_squared = self.length_squared();
+        if length_squared == T::ZERO { return T::ZERO; }
+
+        let t = (*point - self.a).dot(&self.vector()) / length_squared;
+        if t < T::ZERO { T::ZERO } else if t > T::ONE { T::ONE } else { t }
+    }
+
+    pub fn closest_point(&self, point: &Point2<T, S>) -> Point2<T, S> {
+        self.point_at(self.closest_parameter(point))
+    }
+
+    pub fn distance_to_point(&self, point: &Point2<T, S>) -> T {
+        self.closest_point(point).distance(point)
+    }
+
+    /// Zero when the segments intersect.
+    pub fn distance_to_segment(&self, other: &Self) -> T {
+        if self.intersection(other) != SegmentIntersection::None { return T::ZERO; }
+
+        let distances = [
+            self.distance_to_point(&other.a),
+            self.distance_to_point(&other.b),
+            other.distance_to_point(&self.a),
+            other.distance_to_point(&self.b),
+        ];
+        distances.into_iter().fold(distances[0], |min, distance| if distance < min { distance } else { min })
+    }
+
+    /// Touching endpoints intersect. Segments within a small angle of each other are
+    /// parallel, and overlap when also within a small distance, rather than crossing
+    /// at a point made up by rounding.
+    pub fn intersection(&self, other: &Self) -> SegmentIntersection<T, S> {
+        let (r, s) = (self.vector(), other.vector());
+        let (zero, one) = (T::ZERO, T::ONE);
+
+        // Degenerate segments are points, which intersect when lying on the other segment.
+        if r.magnitude_squared() == zero {
+            return if other.closest_point(&self.a) == self.a { SegmentIntersection::Point(self.a) }
+            else { SegmentIntersection::None };
+        }
+        if s.magnitude_squared() == zero {
+            return if self.closest_point(&other.a) == other.a { SegmentIntersection::Point(other.a) }
+            else { SegmentIntersection::None };
+        }
+
+        match line_crossing(&self.a, &r, other) {
+            LineCrossing::Parallel => SegmentIntersection::None,
+            // Overlap of [0, 1] with the other segment expressed along `r`.
+            LineCrossing::Collinear(t0, t1) => {
+                let (t_min, t_max) = if t0 < t1 { (t0, t1) } else { (t1, t0) };
+                let start = if t_min > zero { t_min } else { zero };
+                let end = if t_max < one { t_max } else { one };
+
+                if start > end { SegmentIntersection::None }
+                else if start == end { SegmentIntersection::Point(self.point_at(start)) }
+                else { SegmentIntersection::Overlap(Segment2::new(self.point_at(start), self.point_at(end))) }
+            }
+            LineCrossing::Crossing(t, u) => {
+                if t >= zero && t <= one && u >= zero && u <= one { SegmentIntersection::Point(self.point_at(t)) }
+                else { SegmentIntersection::None }
+            }
+        }
+    }
+}
+
+impl<T: Copy, S> Copy for SegmentIntersection<T, S> {}
+
+impl<T: Copy, S> Clone for SegmentIntersection<T, S> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: PartialEq, S> PartialEq for SegmentIntersection<T, S> {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (SegmentIntersection::None, SegmentIntersection::None) => true,
+            (SegmentIntersection::Point(p1), SegmentIntersection::Point(p2)) => p1 == p2,
+            (SegmentIntersection::Overlap(s1), SegmentIntersection::Overlap(s2)) => s1 == s2,
+            _ => false,
+        }
+    }
+}
+
+impl<T: Debug, S> Debug for SegmentIntersection<T, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SegmentIntersection::None => f.write_str("None"),
+            SegmentIntersection::Point(point) => f.debug_tuple("Point").field(point).finish(),
+            SegmentIntersection::Overlap(segment) => f.debug_tuple("Overlap").field(segment).finish(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    //  ___________________
+    //
+    //  TESTS OVER SEGMENTS
+    //  ___________________
+    //  Segment2 Implements :
+    //  - vector, length, length_squared, midpoint
+    //  - point_at          (Self, f32) -> Point2
+    //  - closest_point     (Self, Point2) -> Point2
+    //  - distance_to_point (Self, Point2) -> f32
+    //  - distance_to_segment (Self, Self) -> f32
+    //  - intersection      (Self, Self) -> SegmentIntersection : None, Point or Overlap
+
+    use super::{Segment2, SegmentIntersection};
+    use crate::point2::Point2;
+    use crate::vector2::Vector2;
+
+    #[test]
+    fn segment2_should_implement_measures() {
+        let segment1 = Segment2::new(Point2::new(1., 1.), Point2::new(4., 5.));
+        assert_eq!(Vector2::new(3., 4.), segment1.vector());
+        assert_eq!(5., segment1.length());
+        assert_eq!(25., segment1.length_squared());
+        assert_eq!(Point2::new(2.5, 3.), segment1.midpoint());
+        assert_eq!(Point2::new(4., 5.), segment1.point_at(1.));
+    }
+
+    #[test]
+    fn segment2_should_implement_closest_point() {
+        let segment1 = Segment2::new(Point2::new(0., 0.), Point2::new(4., 0.));
+        assert_eq!(Point2::new(2., 0.), segment1.closest_point(&Point2::new(2., 3.)));
+        assert_eq!(Point2::new(0., 0.), segment1.closest_point(&Point2::new(-2., 3.)));
+        assert_eq!(Point2::new(4., 0.), segment1.closest_point(&Point2::new(9., -1.)));
+
+        let degenerate = Segment2::new(Point2::new(1., 1.), Point2::new(1., 1.));
+        assert_eq!(Point2::new(1., 1.), degenerate.closest_point(&Point2::new(5., 5.)));
+    }
+
+    #[test]
+    fn segment2_should_implement_distance_to_point() {
+        let segment1 = Segment2::new(Point2::new(0., 0.), Point2::new(4., 0.));
+        assert_eq!(3., segment1.distance_to_point(&Point2::new(2., 3.)));
+        assert_eq!(5., segment1.distance_to_point(&Point2::new(7., 4.)));
+        assert_eq!(0., segment1.distance_to_point(&Point2::new(1., 0.)));
+    }
+
+    #[test]
+    fn segment2_should_intersect_crossing_segments() {
+        let segment1 = Segment2::new(Point2::new(0., 0.), Point2::new(4., 4.));
+        let segment2 = Segment2::new(Point2::new(0., 4.), Point2::new(4., 0.));
+        assert_eq!(SegmentIntersection::Point(Point2::new(2., 2.)), segment1.intersection(&segment2));
+        assert_eq!(SegmentIntersection::Point(Point2::new(2., 2.)), segment2.intersection(&segment1));
+
+        let touching = Segment2::new(Point2::new(4., 4.), Point2::new(6., 0.));
+        assert_eq!(SegmentIntersection::Point(Point2::new(4., 4.)), segment1.intersection(&touching));
+
+        let short = Segment2::new(Point2::new(0., 4.), Point2::new(1., 3.));
+        assert_eq!(SegmentIntersection::None, segment1.intersection(&short));
+    }
+
+    #[test]
+    fn segment2_should_not_intersect_parallel_segments() {
+        let segment1 = Segment2::new(Point2::new(0., 0.), Point2::new(4., 0.));
+        assert_eq!(SegmentIntersection::None, segment1.intersection(&Segment2::new(Point2::new(0., 1.), Point2::new(4., 1.))));
+    }
+
+    #[test]
+    fn segment2_should_intersect_collinear_segments() {
+        let segment1 = Segment2::new(Point2::new(0., 0.), Point2::new(4., 0.));
+        assert_eq!(
+            SegmentIntersection::Overlap(Segment2::new(Point2::new(2., 0.), Point2::new(4., 0.))),
+            segment1.intersection(&Segment2::new(Point2::new(6., 0.), Point2::new(2., 0.)))
+        );
+        assert_eq!(
+            SegmentIntersection::Overlap(Segment2::new(Point2::new(1., 0.), Point2::new(3., 0.))),
+            segment1.intersection(&Segment2::new(Point2::new(1., 0.), Point2::new(3., 0.)))
+        );
+        assert_eq!(
+            SegmentIntersection::Point(Point2::new(4., 0.)),
+            segment1.intersection(&Segment2::new(Point2::new(4., 0.), Point2::new(7., 0.)))
+        );
+        assert_eq!(SegmentIntersection::None, segment1.intersection(&Segment2::new(Point2::new(5., 0.), Point2::new(7., 0.))));
+    }
+
+    #[test]
+    fn segment2_should_treat_nearly_collinear_segments_as_collinear() {
+        // On the same line, but rounding leaves a non zero determinent.
+        let segment1 = Segment2::new(Point2::new(0.1, 0.3), Point2::new(0.3, 0.9));
+        let segment2 = Segment2::new(Point2::new(0.2, 0.6), Point2::new(0.5, 1.5));
+        let SegmentIntersection::Overlap(overlap) = segment1.intersection(&segment2) else { panic!("expected an overlap") };
+        crate::assert_vec_approx_eq!(Point2::new(0.2, 0.6), overlap.a);
+        crate::assert_vec_approx_eq!(Point2::new(0.3, 0.9), overlap.b);
+
+        let shifted = Segment2::new(Point2::new(0.1, 0.4), Point2::new(0.3, 1.));
+        assert_eq!(SegmentIntersection::None, segment1.intersection(&shifted));
+
+        let nearly_parallel = Segment2::new(Point2::new(0., 1.), Point2::new(4., 1.000001));
+        assert_eq!(SegmentIntersection::None, Segment2::new(Point2::new(0., 0.), Point2::new(4., 0.)).intersection(&nearly_parallel));
+    }
+
+    #[test]
+    fn segment2_should_intersect_degenerate_segments() {
+        let segment1 = Segment2::new(Point2::new(0., 0.), Point2::new(4., 0.));
+        let on_segment = Segment2::new(Point2::new(2., 0.), Point2::new(2., 0.));
+        let off_segment = Segment2::new(Point2::new(2., 1.), Point2::new(2., 1.));
+        assert_eq!(SegmentIntersection::Point(Point2::new(2., 0.)), segment1.intersection(&on_segment));
+        assert_eq!(SegmentIntersection::Point(Point2::new(2., 0.)), on_segment.intersection(&segment1));
+        assert_eq!(SegmentIntersection::None, segment1.intersection(&off_segment));
+        assert_eq!(SegmentIntersection::None, on_segment.intersection(&off_segment));
+    }
+
+    #[test]
+    fn segment2_should_implement_distance_to_segment() {
+        let segment1 = Segment2::new(Point2::new(0., 0.), Point2::new(4., 0.));
+        assert_eq!(2., segment1.distance_to_segment(&Segment2::new(Point2::new(1., 2.), Point2::new(3., 5.))));
+        assert_eq!(0., segment1.distance_to_segment(&Segment2::new(Point2::new(1., -1.), Point2::new(3., 1.))));
+        assert_eq!(5., segment1.distance_to_segment(&Segment2::new(Point2::new(7., 4.), Point2::new(9., 4.))));
+        assert_eq!(1., segment1.distance_to_segment(&Segment2::new(Point2::new(2., 1.), Point2::new(6., 1.))));
+    }
+}
@@ -0,0 +1,725 @@
+use crate::point2::Point2;
+use crate::scalar::Real;
+use crate::segment::{impl_shape_traits, Segment2};
+use crate::space::UnknownSpace;
+use crate::unit_vector2::UnitVector2;
+use crate::vector2::Vector2;
+
+//  Shapes are solid : a point inside is contained and is its own closest point,
+//  and shapes whose boundaries only touch still overlap.
+
+pub struct Circle<T = f32, S = UnknownSpace> {
+    pub center: Point2<T, S>,
+    pub radius: T,
+}
+
+/// Axis aligned box, `min` being the lower left corner and `max` the upper right one.
+pub struct Aabb<T = f32, S = UnknownSpace> {
+    pub min: Point2<T, S>,
+    pub max: Point2<T, S>,
+}
+
+/// Box of `half_extents` around `center`, turned so that its local x axis is `axis`.
+pub struct Obb<T = f32, S = UnknownSpace> {
+    pub center: Point2<T, S>,
+    pub half_extents: Vector2<T, S>,
+    pub axis: UnitVector2<T, S>,
+}
+
+/// Every point within `radius` of `segment`, a rectangle with rounded ends.
+pub struct Capsule<T = f32, S = UnknownSpace> {
+    pub segment: Segment2<T, S>,
+    pub radius: T,
+}
+
+impl_shape_traits!(Circle { center, radius });
+impl_shape_traits!(Aabb { min, max });
+impl_shape_traits!(Obb { center, half_extents, axis });
+impl_shape_traits!(Capsule { segment, radius });
+
+/// Whether two shapes share at least one point.
+pub trait Overlaps<Rhs = Self> {
+    fn overlaps(&self, other: &Rhs) -> bool;
+}
+
+fn half<T: Real>() -> T {
+    T::ONE / (T::ONE + T::ONE)
+}
+
+fn clamp<T: Real>(value: T, lower: T, upper: T) -> T {
+    if value < lower { lower } else if value > upper { upper } else { value }
+}
+
+fn min<T: Real>(a: T, b: T) -> T {
+    if b < a { b } else { a }
+}
+
+fn max<T: Real>(a: T, b: T) -> T {
+    if b > a { b } else { a }
+}
+
+/// `point` itself when within `radius` of `anchor`, else the point at `radius` towards it.
+fn closest_within<T: Real, S>(anchor: &Point2<T, S>, radius: T, point: &Point2<T, S>) -> Point2<T, S> {
+    let offset = *point - *anchor;
+    if offset.magnitude_squared() <= radius * radius { *point }
+    else { *anchor + offset.with_magnitude(radius) }
+}
+
+impl<T: Real, S> Circle<T, S> {
+    pub fn new(center: Point2<T, S>, radius: T) -> Self {
+        debug_assert!(radius >= T::ZERO, "Circle with a negative radius");
+        Circle{center, radius}
+    }
+
+    pub fn contains_point(&self, point: &Point2<T, S>) -> bool {
+        (*point - self.center).magnitude_squared() <= self.radius * self.radius
+    }
+
+    pub fn closest_point(&self, point: &Point2<T, S>) -> Point2<T, S> {
+        closest_within(&self.center, self.radius, point)
+    }
+
+    pub fn area(&self) -> T {
+        T::PI * self.radius * self.radius
+    }
+
+    pub fn perimeter(&self) -> T {
+        (T::PI + T::PI) * self.radius
+    }
+
+    pub fn bounding_box(&self) -> Aabb<T, S> {
+        Aabb::from_center(self.center, Vector2::in_space(self.radius, self.radius))
+    }
+
+    /// Grows the radius by `margin`, a negative margin shrinks it down to the center.
+    pub fn expanded(&self, margin: T) -> Self {
+        Circle::new(self.center, max(self.radius + margin, T::ZERO))
+    }
+
+    /// Smallest circle enclosing both.
+    pub fn merged(&self, other: &Self) -> Self {
+        let offset = other.center - self.center;
+        let distance = offset.magnitude();
+        if distance + other.radius <= self.radius { return *self; }
+        if distance + self.radius <= other.radius { return *other; }
+
+        // Neither encloses the other, so the centers are apart.
+        let radius = (distance + self.radius + other.radius) * half();
+        Circle::new(self.center + offset * ((radius - self.radius) / distance), radius)
+    }
+}
+
+impl<T: Real, S> Aabb<T, S> {
+    pub fn new(min: Point2<T, S>, max: Point2<T, S>) -> Self {
+        debug_assert!(min.x() <= max.x() && min.y() <= max.y(), "Aabb with min > max");
+        Aabb{min, max}
+    }
+
+    /// From any two opposite corners.
+    pub fn from_corners(corner1: Point2<T, S>, corner2: Point2<T, S>) -> Self {
+        let (corner1, corner2) = (corner1.to_vector(), corner2.to_vector());
+        Aabb::new(Point2::from_vector(&corner1.min(&corner2)), Point2::from_vector(&corner1.max(&corner2)))
+    }
+
+    pub fn from_center(center: Point2<T, S>, half_extents: Vector2<T, S>) -> Self {
+        Aabb::new(center - half_extents, center + half_extents)
+    }
+
+    /// Smallest box holding every point, `None` for no points.
+    pub fn from_points(points: &[Point2<T, S>]) -> Option<Self> {
+        let (first, rest) = points.split_first()?;
+        Some(rest.iter().fold(Aabb::new(*first, *first), |aabb, point| aabb.expanded_to(point)))
+    }
+
+    pub fn center(&self) -> Point2<T, S> {
+        self.min.lerp(&self.max, half())
+    }
+
+    /// Width and height.
+    pub fn size(&self) -> Vector2<T, S> {
+        self.max - self.min
+    }
+
+    pub fn half_extents(&self) -> Vector2<T, S> {
+        self.size() * half::<T>()
+    }
+
+    pub fn contains_point(&self, point: &Point2<T, S>) -> bool {
+        point.x() >= self.min.x() && point.x() <= self.max.x()
+            && point.y() >= self.min.y() && point.y() <= self.max.y()
+    }
+
+    pub fn closest_point(&self, point: &Point2<T, S>) -> Point2<T, S> {
+        Point2::from_vector(&point.to_vector().clamp(&self.min.to_vector(), &self.max.to_vector()))
+    }
+
+    pub fn area(&self) -> T {
+        let size = self.size();
+        size.x() * size.y()
+    }
+
+    pub fn perimeter(&self) -> T {
+        let size = self.size();
+        (size.x() + size.y()) * (T::ONE + T::ONE)
+    }
+
+    pub fn bounding_box(&self) -> Self {
+        *self
+    }
+
+    /// Moves every side outwards by `margin`, a negative margin shrinks the box.
+    /// Sides shrinking past each other collapse onto the center.
+    pub fn expanded(&self, margin: T) -> Self {
+        let (center, margin) = (self.center().to_vector(), Vector2::in_space(margin, margin));
+        Aabb::new(
+            Point2::from_vector(&(self.min - margin).to_vector().min(&center)),
+            Point2::from_vector(&(self.max + margin).to_vector().max(&center)),
+        )
+    }
+
+    /// Smallest box holding both `self` and `point`.
+    pub fn expanded_to(&self, point: &Point2<T, S>) -> Self {
+        let point = point.to_vector();
+        Aabb::new(
+            Point2::from_vector(&self.min.to_vector().min(&point)),
+            Point2::from_vector(&self.max.to_vector().max(&point)),
+        )
+    }
+
+    /// Smallest box holding both.
+    pub fn merged(&self, other: &Self) -> Self {
+        self.expanded_to(&other.min).expanded_to(&other.max)
+    }
+
+    /// The same box, unrotated.
+    pub fn to_obb(&self) -> Obb<T, S> {
+        Obb::new(self.center(), self.half_extents(), UnitVector2::x_axis())
+    }
+}
+
+impl<T: Real, S> Obb<T, S> {
+    pub fn new(center: Point2<T, S>, half_extents: Vector2<T, S>, axis: UnitVector2<T, S>) -> Self {
+        debug_assert!(half_extents.x() >= T::ZERO && half_extents.y() >= T::ZERO, "Obb with negative half extents");
+        Obb{center, half_extents, axis}
+    }
+
+    /// Turned counter-clockwise by `radians` from the x axis.
+    pub fn from_rotation(center: Point2<T, S>, half_extents: Vector2<T, S>, radians: T) -> Self {
+        Obb::new(center, half_extents, UnitVector2::from_angle(radians))
+    }
+
+    /// Local x and y axes.
+    pub fn axes(&self) -> (UnitVector2<T, S>, UnitVector2<T, S>) {
+        (self.axis, UnitVector2::new_unchecked(self.axis.to_vector().perp_ccw()))
+    }
+
+    /// Counter-clockwise, starting from the local lower left corner.
+    pub fn corners(&self) -> [Point2<T, S>; 4] {
+        let (x_axis, y_axis) = self.axes();
+        let x = x_axis * self.half_extents.x();
+        let y = y_axis * self.half_extents.y();
+        [self.center - x - y, self.center + x - y, self.center + x + y, self.center - x + y]
+    }
+
+    /// Half of the box's shadow on `axis`.
+    fn radius_along(&self, axis: &Vector2<T, S>) -> T {
+        let (x_axis, y_axis) = self.axes();
+        self.half_extents.x() * x_axis.to_vector().dot(axis).abs()
+            + self.half_extents.y() * y_axis.to_vector().dot(axis).abs()
+    }
+
+    /// Zero when the segment touches the box.
+    fn distance_to_segment(&self, segment: &Segment2<T, S>) -> T {
+        if self.contains_point(&segment.a) || self.contains_point(&segment.b) { return T::ZERO; }
+
+        let corners = self.corners();
+        let mut distance = Segment2::new(corners[3], corners[0]).distance_to_segment(segment);
+        for i in 0..3 {
+            let edge_distance = Segment2::new(corners[i], corners[i + 1]).distance_to_segment(segment);
+            if edge_distance < distance { distance = edge_distance; }
+        }
+        distance
+    }
+
+    pub fn contains_point(&self, point: &Point2<T, S>) -> bool {
+        let (x_axis, y_axis) = self.axes();
+        let offset = *point - self.center;
+        offset.dot(&x_axis.to_vector()).abs() <= self.half_extents.x()
+            && offset.dot(&y_axis.to_vector()).abs() <= self.half_extents.y()
+    }
+
+    pub fn closest_point(&self, point: &Point2<T, S>) -> Point2<T, S> {
+        if self.contains_point(point) { return *point; }
+
+        let (x_axis, y_axis) = self.axes();
+        let (x_extent, y_extent) = (self.half_extents.x(), self.half_extents.y());
+        let offset = *point - self.center;
+        let x = clamp(offset.dot(&x_axis.to_vector()), -x_extent, x_extent);
+        let y = clamp(offset.dot(&y_axis.to_vector()), -y_extent, y_extent);
+        self.center + x_axis * x + y_axis * y
+    }
+
+    pub fn area(&self) -> T {
+        let two = T::ONE + T::ONE;
+        self.half_extents.x() * self.half_extents.y() * two * two
+    }
+
+    pub fn perimeter(&self) -> T {
+        let two = T::ONE + T::ONE;
+        (self.half_extents.x() + self.half_extents.y()) * two * two
+    }
+
+    pub fn bounding_box(&self) -> Aabb<T, S> {
+        let x = self.radius_along(&Vector2::in_space(T::ONE, T::ZERO));
+        let y = self.radius_along(&Vector2::in_space(T::ZERO, T::ONE));
+        Aabb::from_center(self.center, Vector2::in_space(x, y))
+    }
+
+    /// Moves every side outwards by `margin`, a negative margin shrinks the box.
+    /// Sides shrinking past each other collapse onto the center.
+    pub fn expanded(&self, margin: T) -> Self {
+        let half_extents = self.half_extents + Vector2::in_space(margin, margin);
+        Obb::new(self.center, half_extents.max(&Vector2::in_space(T::ZERO, T::ZERO)), self.axis)
+    }
+
+    /// Box enclosing both, turned halfway between their axes.
+    /// Exact for boxes sharing their axes, not the smallest enclosing box otherwise.
+    pub fn merged(&self, other: &Self) -> Self {
+        // Box axes repeat every quarter turn, take the quarter of `other` nearest to `self`.
+        let axis = self.axis.to_vector();
+        let other_axis = other.axis.to_vector();
+        let nearest = [other_axis, other_axis.perp_ccw(), -other_axis, other_axis.perp_cw()].into_iter()
+            .fold(other_axis, |nearest, candidate| if candidate.dot(&axis) > nearest.dot(&axis) { candidate } else { nearest });
+        let axis = (axis + nearest).try_normalize().unwrap_or(self.axis);
+        let (x_axis, y_axis) = (axis.to_vector(), axis.to_vector().perp_ccw());
+
+        let mut corners = self.corners().into_iter().chain(other.corners()).map(|corner| corner - self.center);
+        let first = corners.next().unwrap_or(Vector2::in_space(T::ZERO, T::ZERO));
+        let first = Vector2::in_space(first.dot(&x_axis), first.dot(&y_axis));
+        let (lower, upper) = corners.fold((first, first), |(lower, upper), corner| {
+            let local = Vector2::in_space(corner.dot(&x_axis), corner.dot(&y_axis));
+            (lower.min(&local), upper.max(&local))
+        });
+
+        let middle = (lower + upper) * half::<T>();
+        Obb::new(self.center + x_axis * middle.x() + y_axis * middle.y(), (upper - lower) * half::<T>(), axis)
+    }
+}
+
+impl<T: Real, S> Capsule<T, S> {
+    pub fn new(a: Point2<T, S>, b: Point2<T, S>, radius: T) -> Self {
+        debug_assert!(radius >= T::ZERO, "Capsule with a negative radius");
+        Capsule{segment: Segment2::new(a, b), radius}
+    }
+
+    pub fn contains_point(&self, point: &Point2<T, S>) -> bool {
+        self.segment.distance_to_point(point) <= self.radius
+    }
+
+    pub fn closest_point(&self, point: &Point2<T, S>) -> Point2<T, S> {
+        closest_within(&self.segment.closest_point(point), self.radius, point)
+    }
+
+    pub fn area(&self) -> T {
+        (self.radius + self.radius) * self.segment.length() + T::PI * self.radius * self.radius
+    }
+
+    pub fn perimeter(&self) -> T {
+        (self.segment.length() + T::PI * self.radius) * (T::ONE + T::ONE)
+    }
+
+    pub fn bounding_box(&self) -> Aabb<T, S> {
+        Aabb::from_corners(self.segment.a, self.segment.b).expanded(self.radius)
+    }
+
+    /// Grows the radius by `margin`, a negative margin shrinks it down to the segment.
+    pub fn expanded(&self, margin: T) -> Self {
+        Capsule::new(self.segment.a, self.segment.b, max(self.radius + margin, T::ZERO))
+    }
+
+    /// Capsule enclosing both, along their two farthest endpoints.
+    /// Exact for collinear capsules, not the smallest enclosing capsule otherwise.
+    pub fn merged(&self, other: &Self) -> Self {
+        let ends = [
+            (self.segment.a, self.radius), (self.segment.b, self.radius),
+            (other.segment.a, other.radius), (other.segment.b, other.radius),
+        ];
+
+        let (mut start, mut end) = (ends[0].0, ends[1].0);
+        for i in 0..4 {
+            for j in i + 1..4 {
+                if (ends[j].0 - ends[i].0).magnitude_squared() > (end - start).magnitude_squared() {
+                    (start, end) = (ends[i].0, ends[j].0);
+                }
+            }
+        }
+
+        // Every end projected along the new segment, and across it with its radius.
+        let axis = (end - start).try_normalize().unwrap_or(UnitVector2::x_axis()).to_vector();
+        let normal = axis.perp_ccw();
+        let (mut along_min, mut along_max) = (T::ZERO, T::ZERO);
+        let (mut across_min, mut across_max) = (T::ZERO, T::ZERO);
+        for (i, (point, radius)) in ends.into_iter().enumerate() {
+            let (along, across) = ((point - start).dot(&axis), (point - start).dot(&normal));
+            if i == 0 {
+                (along_min, along_max, across_min, across_max) = (along, along, across - radius, across + radius);
+            }
+            (along_min, along_max) = (min(along_min, along), max(along_max, along));
+            (across_min, across_max) = (min(across_min, across - radius), max(across_max, across + radius));
+        }
+
+        let across = normal * ((across_min + across_max) * half());
+        Capsule::new(
+            start + axis * along_min + across,
+            start + axis * along_max + across,
+            (across_max - across_min) * half(),
+        )
+    }
+}
+
+impl<T: Real, S> Overlaps for Circle<T, S> {
+    fn overlaps(&self, other: &Self) -> bool {
+        let radii = self.radius + other.radius;
+        (other.center - self.center).magnitude_squared() <= radii * radii
+    }
+}
+
+impl<T: Real, S> Overlaps<Aabb<T, S>> for Circle<T, S> {
+    fn overlaps(&self, other: &Aabb<T, S>) -> bool {
+        self.contains_point(&other.closest_point(&self.center))
+    }
+}
+
+impl<T: Real, S> Overlaps<Obb<T, S>> for Circle<T, S> {
+    fn overlaps(&self, other: &Obb<T, S>) -> bool {
+        self.contains_point(&other.closest_point(&self.center))
+    }
+}
+
+impl<T: Real, S> Overlaps<Capsule<T, S>> for Circle<T, S> {
+    fn overlaps(&self, other: &Capsule<T, S>) -> bool {
+        other.segment.distance_to_point(&self.center) <= self.radius + other.radius
+    }
+}
+
+impl<T: Real, S> Overlaps for Aabb<T, S> {
+    fn overlaps(&self, other: &Self) -> bool {
+        self.min.x() <= other.max.x() && other.min.x() <= self.max.x()
+            && self.min.y() <= other.max.y() && other.min.y() <= self.max.y()
+    }
+}
+
+impl<T: Real, S> Overlaps<Obb<T, S>> for Aabb<T, S> {
+    fn overlaps(&self, other: &Obb<T, S>) -> bool {
+        self.to_obb().overlaps(other)
+    }
+}
+
+impl<T: Real, S> Overlaps<Capsule<T, S>> for Aabb<T, S> {
+    fn overlaps(&self, other: &Capsule<T, S>) -> bool {
+        self.to_obb().overlaps(other)
+    }
+}
+
+impl<T: Real, S> Overlaps for Obb<T, S> {
+    /// Separating axis test over the four box axes.
+    fn overlaps(&self, other: &Self) -> bool {
+        let offset = other.center - self.center;
+        let ((x1, y1), (x2, y2)) = (self.axes(), other.axes());
+        [x1, y1, x2, y2].iter().all(|axis| {
+            let axis = axis.to_vector();
+            offset.dot(&axis).abs() <= self.radius_along(&axis) + other.radius_along(&axis)
+        })
+    }
+}
+
+impl<T: Real, S> Overlaps<Capsule<T, S>> for Obb<T, S> {
+    fn overlaps(&self, other: &Capsule<T, S>) -> bool {
+        self.distance_to_segment(&other.segment) <= other.radius
+    }
+}
+
+impl<T: Real, S> Overlaps for Capsule<T, S> {
+    fn overlaps(&self, other: &Self) -> bool {
+        self.segment.distance_to_segment(&other.segment) <= self.radius + other.radius
+    }
+}
+
+//  Overlapping is symmetric, the remaining pairs swap their operands.
+macro_rules! impl_swapped_overlaps {
+    ($($Shape: ident with $Other: ident),+) => {
+        $(
+            impl<T: Real, S> Overlaps<$Other<T, S>> for $Shape<T, S> {
+                fn overlaps(&self, other: &$Other<T, S>) -> bool {
+                    other.overlaps(self)
+                }
+            }
+        )+
+    };
+}
+
+impl_swapped_overlaps!(Aabb with Circle, Obb with Circle, Capsule with Circle, Obb with Aabb, Capsule with Aabb, Capsule with Obb);
+
+#[cfg(test)]
+mod tests {
+    //  _________________
+    //
+    //  TESTS OVER SHAPES
+    //  _________________
+    //  Circle, Aabb, Obb and Capsule Implement :
+    //  - contains_point    (Self, Point2) -> bool
+    //  - closest_point     (Self, Point2) -> Point2
+    //  - area, perimeter   (Self) -> f32
+    //  - bounding_box      (Self) -> Aabb
+    //  - expanded          (Self, f32) -> Self, clamped at the center
+    //  - merged            (Self, Self) -> Self
+    //  Aabb Implements :
+    //  - from_corners, from_center, from_points, center, size, expanded_to
+    //  Overlaps Implemented between every pair of shapes
+
+    use super::{Aabb, Capsule, Circle, Obb, Overlaps};
+    use crate::point2::Point2;
+    use crate::fixed::Fixed;
+    use crate::scalar::Real;
+    use crate::unit_vector2::UnitVector2;
+    use crate::vector2::Vector2;
+    use std::f32::consts::PI;
+
+
+    #[test]
+    fn circle_should_implement_contains_and_closest_point() {
+        let circle = Circle::new(Point2::new(1., 1.), 2.);
+        assert!(circle.contains_point(&Point2::new(3., 1.)));
+        assert!(!circle.contains_point(&Point2::new(3., 3.)));
+        assert_eq!(Point2::new(2., 2.), circle.closest_point(&Point2::new(2., 2.)));
+        assert_eq!(Point2::new(1., -1.), circle.closest_point(&Point2::new(1., -5.)));
+    }
+
+    #[test]
+    fn circle_should_implement_measures() {
+        let circle = Circle::new(Point2::new(1., 1.), 2.);
+        assert_eq!(4.*PI, circle.area());
+        assert_eq!(4.*PI, circle.perimeter());
+        assert_eq!(Aabb::new(Point2::new(-1., -1.), Point2::new(3., 3.)), circle.bounding_box());
+        assert_eq!(Circle::new(Point2::new(1., 1.), 3.), circle.expanded(1.));
+    }
+
+    #[test]
+    fn circle_should_implement_merged() {
+        let circle1 = Circle::new(Point2::new(0., 0.), 1.);
+        let circle2 = Circle::new(Point2::new(4., 0.), 1.);
+        assert_eq!(Circle::new(Point2::new(2., 0.), 3.), circle1.merged(&circle2));
+
+        let inner = Circle::new(Point2::new(0.5, 0.), 0.5);
+        assert_eq!(circle1, circle1.merged(&inner));
+        assert_eq!(circle1, inner.merged(&circle1));
+    }
+
+    #[test]
+    fn circle_should_implement_expanded() {
+        let circle = Circle::new(Point2::new(1., 2.), 1.);
+        assert_eq!(Circle::new(Point2::new(1., 2.), 1.5), circle.expanded(0.5));
+        assert_eq!(Circle::new(Point2::new(1., 2.), 0.5), circle.expanded(-0.5));
+        assert_eq!(Circle::new(Point2::new(1., 2.), 0.), circle.expanded(-2.));
+    }
+
+    #[test]
+    fn aabb_should_implement_constructors() {
+        let expected = Aabb::new(Point2::new(-1., 0.), Point2::new(3., 2.));
+        assert_eq!(expected, Aabb::from_corners(Point2::new(3., 0.), Point2::new(-1., 2.)));
+        assert_eq!(expected, Aabb::from_center(Point2::new(1., 1.), Vector2::new(2., 1.)));
+        assert_eq!(
+            Some(expected),
+            Aabb::from_points(&[Point2::new(0., 2.), Point2::new(-1., 1.), Point2::new(3., 0.)])
+        );
+        assert_eq!(None, Aabb::<f32>::from_points(&[]));
+    }
+
+    #[test]
+    fn aabb_should_implement_measures() {
+        let aabb1 = Aabb::new(Point2::new(-1., 0.), Point2::new(3., 2.));
+        assert_eq!(Point2::new(1., 1.), aabb1.center());
+        assert_eq!(Vector2::new(4., 2.), aabb1.size());
+        assert_eq!(8., aabb1.area());
+        assert_eq!(12., aabb1.perimeter());
+        assert_eq!(aabb1, aabb1.bounding_box());
+    }
+
+    #[test]
+    fn aabb_should_implement_contains_and_closest_point() {
+        let aabb1 = Aabb::new(Point2::new(-1., 0.), Point2::new(3., 2.));
+        assert!(aabb1.contains_point(&Point2::new(3., 0.)));
+        assert!(!aabb1.contains_point(&Point2::new(3., 2.5)));
+        assert_eq!(Point2::new(0., 1.), aabb1.closest_point(&Point2::new(0., 1.)));
+        assert_eq!(Point2::new(3., 2.), aabb1.closest_point(&Point2::new(5., 4.)));
+        assert_eq!(Point2::new(-1., 1.5), aabb1.closest_point(&Point2::new(-4., 1.5)));
+    }
+
+    #[test]
+    fn aabb_should_implement_merged_and_expanded() {
+        let aabb1 = Aabb::new(Point2::new(0., 0.), Point2::new(1., 1.));
+        assert_eq!(Aabb::new(Point2::new(-1., -1.), Point2::new(2., 2.)), aabb1.expanded(1.));
+        assert_eq!(Aabb::new(Point2::new(0., -2.), Point2::new(1., 1.)), aabb1.expanded_to(&Point2::new(0.5, -2.)));
+        assert_eq!(Aabb::new(Point2::new(0., 0.), Point2::new(3., 4.)), aabb1.merged(&Aabb::new(Point2::new(2., 3.), Point2::new(3., 4.))));
+    }
+
+    #[test]
+    fn aabb_should_collapse_when_shrunk_past_its_center() {
+        let aabb1 = Aabb::new(Point2::new(0., 0.), Point2::new(1., 1.));
+        assert_eq!(Aabb::new(Point2::new(0.5, 0.5), Point2::new(0.5, 0.5)), aabb1.expanded(-1.));
+
+        let aabb2 = Aabb::new(Point2::new(0., 0.), Point2::new(4., 1.));
+        assert_eq!(Aabb::new(Point2::new(1., 0.5), Point2::new(3., 0.5)), aabb2.expanded(-1.));
+    }
+
+    #[test]
+    fn obb_should_implement_corners_and_measures() {
+        let obb = Obb::from_rotation(Point2::new(1., 1.), Vector2::new(2., 1.), PI/2.);
+        assert_eq!(UnitVector2::y_axis(), obb.axis);
+        assert_eq!(
+            [Point2::new(2., -1.), Point2::new(2., 3.), Point2::new(0., 3.), Point2::new(0., -1.)],
+            obb.corners()
+        );
+        assert_eq!(8., obb.area());
+        assert_eq!(12., obb.perimeter());
+        assert_eq!(Aabb::new(Point2::new(0., -1.), Point2::new(2., 3.)), obb.bounding_box());
+        assert_eq!(Vector2::new(3., 2.), obb.expanded(1.).half_extents);
+
+        let bounds = Obb::from_rotation(Point2::new(0., 0.), Vector2::new(1., 1.), PI/4.).bounding_box();
+        crate::assert_vec_approx_eq!(Point2::new(2f32.sqrt(), 2f32.sqrt()), bounds.max);
+    }
+
+    #[test]
+    fn obb_should_implement_expanded() {
+        let obb = Obb::new(Point2::new(1., 1.), Vector2::new(2., 1.), UnitVector2::y_axis());
+        assert_eq!(Obb::new(Point2::new(1., 1.), Vector2::new(1.5, 0.5), UnitVector2::y_axis()), obb.expanded(-0.5));
+        assert_eq!(Obb::new(Point2::new(1., 1.), Vector2::new(0.5, 0.), UnitVector2::y_axis()), obb.expanded(-1.5));
+        assert_eq!(Obb::new(Point2::new(1., 1.), Vector2::new(0., 0.), UnitVector2::y_axis()), obb.expanded(-3.));
+    }
+
+    #[test]
+    fn obb_should_implement_merged() {
+        let obb1 = Obb::new(Point2::new(0., 0.), Vector2::new(1., 1.), UnitVector2::x_axis());
+        let obb2 = Obb::new(Point2::new(4., 1.), Vector2::new(1., 2.), UnitVector2::x_axis());
+        assert_eq!(Obb::new(Point2::new(2., 1.), Vector2::new(3., 2.), UnitVector2::x_axis()), obb1.merged(&obb2));
+
+        // A quarter turn describes the same box.
+        let turned = Obb::new(Point2::new(4., 1.), Vector2::new(2., 1.), UnitVector2::y_axis());
+        let merged = obb1.merged(&turned);
+        assert_eq!(UnitVector2::x_axis(), merged.axis);
+        assert_eq!(Vector2::new(3., 2.), merged.half_extents);
+
+        let tilted = Obb::from_rotation(Point2::new(3., 0.), Vector2::new(1., 0.5), PI/6.);
+        let merged = obb1.merged(&tilted);
+        for corner in obb1.corners().into_iter().chain(tilted.corners()) {
+            assert!(merged.expanded(1e-5).contains_point(&corner));
+        }
+    }
+
+    #[test]
+    fn obb_should_implement_contains_and_closest_point() {
+        let obb = Obb::from_rotation(Point2::new(0., 0.), Vector2::new(1., 1.), PI/4.);
+        assert!(obb.contains_point(&Point2::new(1.4, 0.)));
+        assert!(!obb.contains_point(&Point2::new(1., 1.)));
+        assert_eq!(Point2::new(0.5, 0.5), obb.closest_point(&Point2::new(0.5, 0.5)));
+        crate::assert_vec_approx_eq!(Point2::new(0.5*2f32.sqrt(), 0.5*2f32.sqrt()), obb.closest_point(&Point2::new(2., 2.)));
+        crate::assert_vec_approx_eq!(Point2::new(2f32.sqrt(), 0.), obb.closest_point(&Point2::new(3., 0.)));
+    }
+
+    #[test]
+    fn capsule_should_implement_contains_and_closest_point() {
+        let capsule = Capsule::new(Point2::new(0., 0.), Point2::new(4., 0.), 1.);
+        assert!(capsule.contains_point(&Point2::new(2., 1.)));
+        assert!(capsule.contains_point(&Point2::new(5., 0.)));
+        assert!(!capsule.contains_point(&Point2::new(4.8, 0.8)));
+        assert_eq!(Point2::new(1., 0.5), capsule.closest_point(&Point2::new(1., 0.5)));
+        assert_eq!(Point2::new(2., -1.), capsule.closest_point(&Point2::new(2., -3.)));
+        assert_eq!(Point2::new(-1., 0.), capsule.closest_point(&Point2::new(-5., 0.)));
+    }
+
+    #[test]
+    fn capsule_should_implement_measures() {
+        let capsule = Capsule::new(Point2::new(0., 0.), Point2::new(4., 0.), 1.);
+        assert_eq!(8. + PI, capsule.area());
+        assert_eq!(8. + 2.*PI, capsule.perimeter());
+        assert_eq!(Aabb::new(Point2::new(-1., -1.), Point2::new(5., 1.)), capsule.bounding_box());
+        assert_eq!(2., capsule.expanded(1.).radius);
+        assert_eq!(0.5, capsule.expanded(-0.5).radius);
+        assert_eq!(0., capsule.expanded(-2.).radius);
+    }
+
+    #[test]
+    fn capsule_should_implement_merged() {
+        let capsule1 = Capsule::new(Point2::new(0., 0.), Point2::new(2., 0.), 1.);
+        let capsule2 = Capsule::new(Point2::new(3., 0.), Point2::new(5., 0.), 2.);
+        assert_eq!(Capsule::new(Point2::new(0., 0.), Point2::new(5., 0.), 2.), capsule1.merged(&capsule2));
+
+        let crossing = Capsule::new(Point2::new(1., -2.), Point2::new(1., 3.), 0.5);
+        let merged = capsule1.merged(&crossing);
+        for capsule in [capsule1, crossing] {
+            for end in [capsule.segment.a, capsule.segment.b] {
+                for i in 0..8 {
+                    let point = end + Vector2::from_angle(i as f32 * PI/4.) * capsule.radius;
+                    assert!(merged.expanded(1e-5).contains_point(&point));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn shapes_should_overlap_circles() {
+        let circle = Circle::new(Point2::new(0., 0.), 1.);
+        assert!(circle.overlaps(&Circle::new(Point2::new(2., 0.), 1.)));
+        assert!(!circle.overlaps(&Circle::new(Point2::new(2., 1.), 1.)));
+
+        assert!(circle.overlaps(&Aabb::new(Point2::new(1., -3.), Point2::new(2., 3.))));
+        assert!(!Aabb::new(Point2::new(0.8, 0.8), Point2::new(2., 2.)).overlaps(&circle));
+
+        assert!(circle.overlaps(&Obb::from_rotation(Point2::new(2., 0.), Vector2::new(1., 1.), PI/4.)));
+        assert!(!Obb::from_rotation(Point2::new(1.6, 1.6), Vector2::new(1., 1.), PI/4.).overlaps(&circle));
+
+        let capsule = Capsule::new(Point2::new(-3., 2.), Point2::new(3., 2.), 1.);
+        assert!(circle.overlaps(&capsule));
+        assert!(!capsule.overlaps(&Circle::new(Point2::new(0., -0.5), 1.)));
+    }
+
+    #[test]
+    fn shapes_should_overlap_boxes() {
+        let aabb1 = Aabb::new(Point2::new(0., 0.), Point2::new(2., 2.));
+        assert!(aabb1.overlaps(&Aabb::new(Point2::new(2., 2.), Point2::new(3., 3.))));
+        assert!(!aabb1.overlaps(&Aabb::new(Point2::new(2.5, 0.), Point2::new(3., 3.))));
+
+        // The diamond's tip reaches into the box, its sides stay clear of the corner.
+        assert!(aabb1.overlaps(&Obb::from_rotation(Point2::new(3.2, 1.), Vector2::new(1., 1.), PI/4.)));
+        assert!(!Obb::from_rotation(Point2::new(3.2, 3.2), Vector2::new(1., 1.), PI/4.).overlaps(&aabb1));
+
+        assert!(Obb::from_rotation(Point2::new(0., 0.), Vector2::new(1., 1.), PI/4.).overlaps(&Obb::from_rotation(Point2::new(2.8, 0.), Vector2::new(1., 1.), PI/4.)));
+        assert!(!Obb::from_rotation(Point2::new(0., 0.), Vector2::new(1., 1.), PI/4.).overlaps(&Obb::from_rotation(Point2::new(1.5, 1.5), Vector2::new(1., 1.), PI/4.)));
+    }
+
+    #[test]
+    fn shapes_should_overlap_capsules() {
+        let capsule = Capsule::new(Point2::new(0., 0.), Point2::new(4., 0.), 1.);
+        assert!(capsule.overlaps(&Capsule::new(Point2::new(2., 5.), Point2::new(2., 2.), 1.)));
+        assert!(!capsule.overlaps(&Capsule::new(Point2::new(2., 5.), Point2::new(2., 2.5), 1.)));
+
+        assert!(capsule.overlaps(&Aabb::new(Point2::new(-2., -2.), Point2::new(-0.5, -0.5))));
+        assert!(!Aabb::new(Point2::new(4.8, 0.8), Point2::new(6., 2.)).overlaps(&capsule));
+        assert!(Aabb::new(Point2::new(1., -0.5), Point2::new(2., 0.5)).overlaps(&capsule));
+
+        assert!(capsule.overlaps(&Obb::from_rotation(Point2::new(2., 2.), Vector2::new(1., 1.), PI/4.)));
+        assert!(!Obb::from_rotation(Point2::new(2., 2.5), Vector2::new(1., 1.), PI/4.).overlaps(&capsule));
+        assert!(Obb::from_rotation(Point2::new(6., 0.), Vector2::new(1., 1.), PI/4.).overlaps(&capsule));
+    }
+
+    #[test]
+    fn fixed_shapes_should_overlap() {
+        let point = |x: i16, y: i16| Point2::new(Fixed::from_int(x), Fixed::from_int(y));
+        let circle = Circle::new(point(0, 0), Fixed::from_int(1));
+        let aabb1 = Aabb::new(point(1, -1), point(3, 1));
+        assert!(circle.overlaps(&aabb1));
+        assert!(!circle.overlaps(&Aabb::new(point(1, 1), point(3, 3))));
+        assert!(aabb1.overlaps(&Obb::from_rotation(point(4, 0), Vector2::new(Fixed::from_int(1), Fixed::from_int(1)), Fixed::PI)));
+        assert_eq!(Fixed::from_int(4), aabb1.area());
+    }
+}
@@ -0,0 +1,238 @@
+use crate::point2::Point2;
+use crate::rotation::rotations::sin_cos;
+use crate::scalar::{Real, Scalar};
+use crate::vector2::Vector2;
+use std::fmt::{self, Debug};
+use std::marker::PhantomData;
+
+//  Zero-sized space markers, any type can be used as a space.
+//  UnknownSpace is the default of Vector2 and Point2.
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct UnknownSpace;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct WorldSpace;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ScreenSpace;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct LocalSpace;
+
+/// Affine transform from the space `Src` to the space `Dst`.
+/// This is the checked way of changing space, `cast_space` being the unchecked one.
+pub struct Transform2<T, Src, Dst> {
+    x_axis: Vector2<T>,
+    y_axis: Vector2<T>,
+    translation: Vector2<T>,
+    spaces: PhantomData<(Src, Dst)>,
+}
+
+impl<T: Scalar, Src, Dst> Transform2<T, Src, Dst> {
+    /// `x_axis` and `y_axis` are the images of the `Src` basis vectors, expressed in `Dst`.
+    pub fn new(x_axis: Vector2<T>, y_axis: Vector2<T>, translation: Vector2<T>) -> Self {
+        Transform2{x_axis, y_axis, translation, spaces: PhantomData}
+    }
+
+    pub fn identity() -> Self {
+        Transform2::new(
+            Vector2::new(T::ONE, T::ZERO),
+            Vector2::new(T::ZERO, T::ONE),
+            Vector2::new(T::ZERO, T::ZERO)
+        )
+    }
+
+    pub fn from_translation(translation: Vector2<T>) -> Self {
+        Transform2::new(Vector2::new(T::ONE, T::ZERO), Vector2::new(T::ZERO, T::ONE), translation)
+    }
+
+    pub fn from_scale(scale: Vector2<T>) -> Self {
+        Transform2::new(
+            Vector2::new(scale.x(), T::ZERO),
+            Vector2::new(T::ZERO, scale.y()),
+            Vector2::new(T::ZERO, T::ZERO)
+        )
+    }
+
+    pub fn x_axis(&self) -> Vector2<T> {
+        self.x_axis
+    }
+
+    pub fn y_axis(&self) -> Vector2<T> {
+        self.y_axis
+    }
+
+    pub fn translation(&self) -> Vector2<T> {
+        self.translation
+    }
+
+    /// Vectors are displacements, the translation does not apply to them.
+    pub fn transform_vector(&self, vector: &Vector2<T, Src>) -> Vector2<T, Dst> {
+        (self.x_axis * vector.x() + self.y_axis * vector.y()).cast_space()
+    }
+
+    pub fn transform_point(&self, point: &Point2<T, Src>) -> Point2<T, Dst> {
+        Point2::from_vector(&(self.x_axis * point.x() + self.y_axis * point.y() + self.translation).cast_space())
+    }
+
+    /// Applies `self`, then `next`.
+    pub fn then<Next>(&self, next: &Transform2<T, Dst, Next>) -> Transform2<T, Src, Next> {
+        Transform2::new(
+            next.transform_vector(&self.x_axis.cast_space()).cast_space(),
+            next.transform_vector(&self.y_axis.cast_space()).cast_space(),
+            next.transform_point(&Point2::from_vector(&self.translation.cast_space())).to_vector().cast_space()
+        )
+    }
+}
+
+impl<T: Real, Src, Dst> Transform2<T, Src, Dst> {
+    /// Counter-clockwise rotation, exact for quarter turns.
+    pub fn from_rotation(radians: T) -> Self {
+        let (sin, cos) = sin_cos(radians);
+        Transform2::new(
+            Vector2::new(cos, sin),
+            Vector2::new(-sin, cos),
+            Vector2::new(T::ZERO, T::ZERO)
+        )
+    }
+
+    /// `None` when the transform is degenerate.
+    /// Only for real scalars, integer inverses would truncate to a wrong transform.
+    pub fn inverse(&self) -> Option<Transform2<T, Dst, Src>> {
+        let det = self.x_axis.determinent(&self.y_axis);
+        if det == T::ZERO { return None; }
+
+        let x_axis = Vector2::new(self.y_axis.y(), -self.x_axis.y()) / det;
+        let y_axis = Vector2::new(-self.y_axis.x(), self.x_axis.x()) / det;
+        let translation = x_axis * self.translation.x() + y_axis * self.translation.y();
+        Some(Transform2::new(x_axis, y_axis, Vector2::new(T::ZERO, T::ZERO) - translation))
+    }
+}
+
+impl<T: Copy, Src, Dst> Copy for Transform2<T, Src, Dst> {}
+
+impl<T: Copy, Src, Dst> Clone for Transform2<T, Src, Dst> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: PartialEq, Src, Dst> PartialEq for Transform2<T, Src, Dst> {
+    fn eq(&self, other: &Self) -> bool {
+        self.x_axis == other.x_axis && self.y_axis == other.y_axis && self.translation == other.translation
+    }
+}
+
+impl<T: Debug, Src, Dst> Debug for Transform2<T, Src, Dst> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Transform2")
+            .field("x_axis", &self.x_axis)
+            .field("y_axis", &self.y_axis)
+            .field("translation", &self.translation)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    //  ____________________________
+    //
+    //  TESTS OVER COORDINATE SPACES
+    //  ____________________________
+    //  Vector2 and Point2 Implement :
+    //  - in_space      (T, T) -> Self
+    //  - cast_space    (Self) -> Self in another space
+    //  Transform2 Implements :
+    //  - identity, from_translation, from_scale, from_rotation
+    //  - transform_vector, transform_point
+    //  - then          (Src -> Dst, Dst -> Next) -> (Src -> Next)
+    //  - inverse       (Src -> Dst) -> Option<Dst -> Src>
+
+    use super::{LocalSpace, ScreenSpace, Transform2, WorldSpace};
+    use crate::point2::Point2;
+    use crate::vector2::Vector2;
+    use crate::test_tools;
+    use std::f32::consts::PI;
+
+    type WorldVector = Vector2<f32, WorldSpace>;
+    type ScreenVector = Vector2<f32, ScreenSpace>;
+
+    #[test]
+    fn tagged_vectors_should_be_zero_cost() {
+        assert_eq!(std::mem::size_of::<Vector2>(), std::mem::size_of::<WorldVector>());
+        assert_eq!(std::mem::size_of::<[f32; 2]>(), std::mem::size_of::<WorldVector>());
+    }
+
+    #[test]
+    fn tagged_vectors_should_combine_within_their_space() {
+        let vec1 = WorldVector::in_space(1., 2.);
+        let vec2 = WorldVector::in_space(3., 4.);
+        assert_eq!(WorldVector::in_space(4., 6.), vec1 + vec2);
+        assert_eq!(WorldVector::in_space(2., 4.), vec1 * 2.);
+        assert_eq!(11., vec1.dot(&vec2));
+
+        let point1 = Point2::<f32, WorldSpace>::in_space(1., 1.);
+        assert_eq!(Point2::in_space(2., 3.), point1 + vec1);
+    }
+
+    #[test]
+    fn tagged_vectors_should_implement_cast_space() {
+        let vec1 = WorldVector::in_space(1., 2.);
+        let vec2: ScreenVector = vec1.cast_space();
+        assert_eq!(ScreenVector::in_space(1., 2.), vec2);
+        assert_eq!(Vector2::new(1., 2.), vec2.cast_space());
+
+        let point1: Point2<f32, LocalSpace> = Point2::new(3., 4.).cast_space();
+        assert_eq!(Point2::<f32, LocalSpace>::in_space(3., 4.), point1);
+    }
+
+    #[test]
+    fn transform2_should_map_points_and_vectors() {
+        let world_to_screen: Transform2<f32, WorldSpace, ScreenSpace> =
+            Transform2::<f32, WorldSpace, WorldSpace>::from_scale(Vector2::new(2., -2.))
+                .then(&Transform2::from_translation(Vector2::new(100., 50.)));
+
+        let point1 = Point2::<f32, WorldSpace>::in_space(10., 5.);
+        assert_eq!(Point2::<f32, ScreenSpace>::in_space(120., 40.), world_to_screen.transform_point(&point1));
+
+        let vec1 = WorldVector::in_space(10., 5.);
+        assert_eq!(ScreenVector::in_space(20., -10.), world_to_screen.transform_vector(&vec1));
+    }
+
+    #[test]
+    fn transform2_should_implement_identity() {
+        let identity = Transform2::<f32, WorldSpace, LocalSpace>::identity();
+        let point1 = Point2::<f32, WorldSpace>::in_space(3., -2.);
+        assert_eq!(point1.cast_space::<LocalSpace>(), identity.transform_point(&point1));
+    }
+
+    #[test]
+    fn transform2_should_implement_rotation() {
+        let local_to_world = Transform2::<f32, LocalSpace, WorldSpace>::from_rotation(PI/2.);
+        let vec1 = local_to_world.transform_vector(&Vector2::in_space(1., 0.));
+        assert_eq!(Vector2::in_space(0., 1.), vec1);
+
+        let local_to_world = Transform2::<f32, LocalSpace, WorldSpace>::from_rotation(PI/6.);
+        let vec1 = local_to_world.transform_vector(&Vector2::in_space(1., 0.));
+        test_tools::assert_approx_eq!(0.5, &vec1.y());
+    }
+
+    #[test]
+    fn transform2_should_implement_inverse() {
+        let local_to_world = Transform2::<f32, LocalSpace, WorldSpace>::new(
+            Vector2::new(2., 1.),
+            Vector2::new(-1., 3.),
+            Vector2::new(5., -4.)
+        );
+        let world_to_local = local_to_world.inverse().unwrap();
+
+        let point1 = Point2::<f32, LocalSpace>::in_space(1.5, -2.);
+        let round_trip = world_to_local.transform_point(&local_to_world.transform_point(&point1));
+        test_tools::assert_approx_eq!(1.5, &round_trip.x());
+        test_tools::assert_approx_eq!(-2., &round_trip.y());
+
+        let degenerate = Transform2::<f32, LocalSpace, WorldSpace>::from_scale(Vector2::new(0., 1.));
+        assert_eq!(None, degenerate.inverse());
+    }
+}
@@ -0,0 +1,100 @@
+pub mod swizzles {
+    use crate::vector2::Vector2;
+    use crate::vector3::Vector3;
+    use crate::scalar::Scalar;
+
+    impl<T: Scalar, S> Vector2<T, S> {
+        pub fn xy(&self) -> Self {
+            *self
+        }
+
+        pub fn yx(&self) -> Self {
+            Vector2::in_space(self.y(), self.x())
+        }
+
+        pub fn xx(&self) -> Self {
+            Vector2::in_space(self.x(), self.x())
+        }
+
+        pub fn yy(&self) -> Self {
+            Vector2::in_space(self.y(), self.y())
+        }
+    }
+
+    //  Widening swizzles, `0` marks the inserted zero component.
+    impl<T: Scalar, S> Vector2<T, S> {
+        pub fn xy0(&self) -> Vector3<T> {
+            Vector3::new(self.x(), self.y(), T::ZERO)
+        }
+
+        pub fn x0y(&self) -> Vector3<T> {
+            Vector3::new(self.x(), T::ZERO, self.y())
+        }
+
+        pub fn yx0(&self) -> Vector3<T> {
+            Vector3::new(self.y(), self.x(), T::ZERO)
+        }
+
+        pub fn y0x(&self) -> Vector3<T> {
+            Vector3::new(self.y(), T::ZERO, self.x())
+        }
+    }
+
+    impl<T: Scalar> Vector3<T> {
+        pub fn xy(&self) -> Vector2<T> {
+            Vector2::new(self.x(), self.y())
+        }
+
+        pub fn xz(&self) -> Vector2<T> {
+            Vector2::new(self.x(), self.z())
+        }
+
+        pub fn yz(&self) -> Vector2<T> {
+            Vector2::new(self.y(), self.z())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    //  ________________________
+    //
+    //  TESTS OVER SWIZZLE TOOLS
+    //  ________________________
+    //  Vector2 Implements :
+    //  - xy, yx, xx, yy            (Self) -> Self
+    //  - xy0, x0y, yx0, y0x        (Self) -> Vector3
+    //  Vector3 Implements :
+    //  - xy, xz, yz                (Self) -> Vector2
+
+    use crate::vector2::Vector2;
+    use crate::vector3::Vector3;
+
+    #[test]
+    fn vector2_should_implement_swizzles() {
+        let vec1 = Vector2::new(1., 2.);
+        assert_eq!(Vector2::new(1., 2.), vec1.xy());
+        assert_eq!(Vector2::new(2., 1.), vec1.yx());
+        assert_eq!(Vector2::new(1., 1.), vec1.xx());
+        assert_eq!(Vector2::new(2., 2.), vec1.yy());
+        assert_eq!(vec1, vec1.yx().yx());
+    }
+
+    #[test]
+    fn vector2_should_implement_widening_swizzles() {
+        let vec1 = Vector2::new(1., 2.);
+        assert_eq!(Vector3::new(1., 2., 0.), vec1.xy0());
+        assert_eq!(Vector3::new(1., 0., 2.), vec1.x0y());
+        assert_eq!(Vector3::new(2., 1., 0.), vec1.yx0());
+        assert_eq!(Vector3::new(2., 0., 1.), vec1.y0x());
+    }
+
+    #[test]
+    fn vector3_should_implement_narrowing_swizzles() {
+        let vec1 = Vector3::new(1., 2., 3.);
+        assert_eq!(Vector2::new(1., 2.), vec1.xy());
+        assert_eq!(Vector2::new(1., 3.), vec1.xz());
+        assert_eq!(Vector2::new(2., 3.), vec1.yz());
+        assert_eq!(Vector2::new(1., 2.), Vector2::new(1., 2.).x0y().xz());
+    }
+}
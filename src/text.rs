@@ -0,0 +1,165 @@
+use crate::error::ParseVector2Error;
+use crate::scalar::Scalar;
+use crate::vector2::Vector2;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+/// Text layouts of a `Vector2`, all of them are accepted by `FromStr`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum TextFormat {
+    /// `(1.5, -2)`, the `Display` layout.
+    Parenthesized,
+    /// `1.5,-2`
+    Comma,
+    /// `[1.5 -2]`
+    Bracketed,
+}
+
+/// `Display` adapter returned by `Vector2::format`, precision flags are forwarded to the components.
+pub struct FormattedVector2<'a, T, S> {
+    vector: &'a Vector2<T, S>,
+    format: TextFormat,
+}
+
+impl<T: Scalar + Display, S> Display for FormattedVector2<'_, T, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (open, separator, close) = match self.format {
+            TextFormat::Parenthesized => ("(", ", ", ")"),
+            TextFormat::Comma => ("", ",", ""),
+            TextFormat::Bracketed => ("[", " ", "]"),
+        };
+
+        let (x, y) = (self.vector.x(), self.vector.y());
+        match f.precision() {
+            Some(precision) => write!(f, "{}{:.*}{}{:.*}{}", open, precision, x, separator, precision, y, close),
+            None => write!(f, "{}{}{}{}{}", open, x, separator, y, close),
+        }
+    }
+}
+
+impl<T: Scalar, S> Vector2<T, S> {
+    pub fn format(&self, format: TextFormat) -> FormattedVector2<'_, T, S> {
+        FormattedVector2{vector: self, format}
+    }
+}
+
+impl<T: Scalar + Display, S> Display for Vector2<T, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.format(TextFormat::Parenthesized).fmt(f)
+    }
+}
+
+/// Reads `(1.5, -2)`, `1.5,-2` or `[1.5 -2]` : optional parentheses or brackets,
+/// components separated by a comma or by whitespace.
+impl<T: Scalar + FromStr, S> FromStr for Vector2<T, S> {
+    type Err = ParseVector2Error;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let text = text.trim();
+        let inner = match (text.chars().next(), text.chars().last()) {
+            (Some('('), Some(')')) | (Some('['), Some(']')) if text.len() >= 2 => &text[1..text.len() - 1],
+            (Some('(' | '['), _) | (_, Some(')' | ']')) => return Err(ParseVector2Error::UnbalancedDelimiter),
+            _ => text,
+        };
+
+        let components: Vec<&str> =
+            if inner.contains(',') { inner.split(',').map(str::trim).collect() }
+            else { inner.split_whitespace().collect() };
+        if components.len() != 2 {
+            return Err(ParseVector2Error::ComponentCount(components.len()));
+        }
+
+        let parse = |index: usize| components[index].parse::<T>().map_err(|_| {
+            ParseVector2Error::InvalidComponent{index, text: components[index].to_string()}
+        });
+        Ok(Vector2::in_space(parse(0)?, parse(1)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    //  __________________________
+    //
+    //  TESTS OVER TEXT CONVERSION
+    //  __________________________
+    //  Vector2 Implements :
+    //  - Display       (1.5, -2), with precision support
+    //  - format        (Self, TextFormat) -> impl Display
+    //  - FromStr       "(1.5, -2)", "1.5,-2", "[1.5 -2]" -> Result<Self, ParseVector2Error>
+
+    use super::TextFormat;
+    use crate::error::ParseVector2Error;
+    use crate::vector2::{IVec2, Vector2};
+
+    #[test]
+    fn vector2_should_implement_display() {
+        assert_eq!("(1.5, -2)", Vector2::new(1.5, -2.).to_string());
+        assert_eq!("(3, 4)", IVec2::new(3, 4).to_string());
+    }
+
+    #[test]
+    fn vector2_display_should_support_precision() {
+        assert_eq!("(1.50, -2.00)", format!("{:.2}", Vector2::new(1.5, -2.)));
+        assert_eq!("(0.333, 1.000)", format!("{:.3}", Vector2::new(1./3., 1.)));
+    }
+
+    #[test]
+    fn vector2_should_implement_format() {
+        let vec1 = Vector2::new(1.5, -2.);
+        assert_eq!("(1.5, -2)", vec1.format(TextFormat::Parenthesized).to_string());
+        assert_eq!("1.5,-2", vec1.format(TextFormat::Comma).to_string());
+        assert_eq!("[1.5 -2]", vec1.format(TextFormat::Bracketed).to_string());
+        assert_eq!("[1.5 -2.0]", format!("{:.1}", vec1.format(TextFormat::Bracketed)));
+    }
+
+    #[test]
+    fn vector2_debug_should_only_print_components() {
+        assert_eq!("Vector2 { x: 1.5, y: -2.0 }", format!("{:?}", Vector2::new(1.5, -2.)));
+    }
+
+    #[test]
+    fn vector2_should_implement_from_str() {
+        let expected = Ok(Vector2::new(1.5, -2.));
+        assert_eq!(expected, "(1.5, -2)".parse());
+        assert_eq!(expected, "1.5,-2".parse());
+        assert_eq!(expected, "[1.5 -2]".parse());
+        assert_eq!(expected, "  ( 1.5 ,-2 ) ".parse());
+        assert_eq!(expected, "[1.5, -2]".parse());
+        assert_eq!(Ok(IVec2::new(3, -4)), "(3, -4)".parse());
+    }
+
+    #[test]
+    fn vector2_from_str_should_round_trip() {
+        let vec1 = Vector2::new(0.1, -123.456);
+        for format in [TextFormat::Parenthesized, TextFormat::Comma, TextFormat::Bracketed] {
+            assert_eq!(Ok(vec1), vec1.format(format).to_string().parse());
+        }
+    }
+
+    #[test]
+    fn vector2_from_str_should_report_errors() {
+        assert_eq!(Err(ParseVector2Error::UnbalancedDelimiter), "(1.5, -2".parse::<Vector2>());
+        assert_eq!(Err(ParseVector2Error::UnbalancedDelimiter), "[1.5 -2)".parse::<Vector2>());
+        assert_eq!(Err(ParseVector2Error::ComponentCount(1)), "(1.5)".parse::<Vector2>());
+        assert_eq!(Err(ParseVector2Error::ComponentCount(3)), "1, 2, 3".parse::<Vector2>());
+        assert_eq!(Err(ParseVector2Error::ComponentCount(0)), "".parse::<Vector2>());
+        assert_eq!(Err(ParseVector2Error::ComponentCount(3)), "1,,2".parse::<Vector2>());
+        assert_eq!(
+            Err(ParseVector2Error::InvalidComponent{index: 1, text: "".to_string()}),
+            "(1.5, )".parse::<Vector2>()
+        );
+        assert_eq!(
+            Err(ParseVector2Error::InvalidComponent{index: 1, text: "abc".to_string()}),
+            "(1.5, abc)".parse::<Vector2>()
+        );
+        assert_eq!(
+            Err(ParseVector2Error::InvalidComponent{index: 0, text: "1.5".to_string()}),
+            "(1.5, 2)".parse::<IVec2>()
+        );
+    }
+
+    #[test]
+    fn parse_vector2_error_should_implement_display() {
+        assert_eq!("expected 2 vector components, found 3", ParseVector2Error::ComponentCount(3).to_string());
+    }
+}
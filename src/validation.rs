@@ -0,0 +1,162 @@
+pub mod checks {
+    use crate::vector2::Vector2;
+    use crate::error::Vector2Error;
+    use crate::scalar::Scalar;
+
+    impl<T: Scalar, S> Vector2<T, S> {
+        pub fn try_new(x: T, y: T) -> Result<Self, Vector2Error> {
+            if x.is_nan() || y.is_nan() { return Err(Vector2Error::NaN); }
+            if !x.is_finite() || !y.is_finite() { return Err(Vector2Error::Infinite); }
+            Ok(Vector2::in_space(x, y))
+        }
+
+        pub fn is_finite(&self) -> bool {
+            self.x().is_finite() && self.y().is_finite()
+        }
+
+        pub fn is_nan(&self) -> bool {
+            self.x().is_nan() || self.y().is_nan()
+        }
+    }
+}
+
+pub mod checked_ops {
+    use crate::vector2::Vector2;
+    use crate::error::Vector2Error;
+    use crate::scalar::Scalar;
+
+    //  Checked counterparts of the Div and Rem operators, which would otherwise
+    //  yield infinities or NaN on a zero divisor, or overflow on integers.
+    impl<T: Scalar, S> Vector2<T, S> {
+        pub fn try_div(&self, other: &Self) -> Result<Self, Vector2Error> {
+            if other.x() == T::ZERO || other.y() == T::ZERO { return Err(Vector2Error::DivisionByZero); }
+            Ok(Vector2::in_space(
+                self.x().checked_quotient(other.x()).ok_or(Vector2Error::Overflow)?,
+                self.y().checked_quotient(other.y()).ok_or(Vector2Error::Overflow)?
+            ))
+        }
+
+        pub fn try_div_k(&self, k: T) -> Result<Self, Vector2Error> {
+            self.try_div(&Vector2::in_space(k, k))
+        }
+
+        pub fn try_rem(&self, other: &Self) -> Result<Self, Vector2Error> {
+            if other.x() == T::ZERO || other.y() == T::ZERO { return Err(Vector2Error::DivisionByZero); }
+            Ok(Vector2::in_space(
+                self.x().checked_remainder(other.x()).ok_or(Vector2Error::Overflow)?,
+                self.y().checked_remainder(other.y()).ok_or(Vector2Error::Overflow)?
+            ))
+        }
+
+        pub fn try_rem_k(&self, k: T) -> Result<Self, Vector2Error> {
+            self.try_rem(&Vector2::in_space(k, k))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    //  ___________________________
+    //
+    //  TESTS OVER VALIDATION TOOLS
+    //  ___________________________
+    //  Vector2 Implements :
+    //  - try_new       (T, T) -> Result<Self, Vector2Error>, in any space
+    //  - is_finite     (Self) -> bool
+    //  - is_nan        (Self) -> bool
+    //  - try_div, try_rem          (Self, Self) -> Result<Self, Vector2Error>
+    //  - try_div_k, try_rem_k      (Self, T) -> Result<Self, Vector2Error>
+    //  - with the "validate" feature, debug-asserts finite components
+    //  - try_div, try_rem report Overflow on integers, as i32::MIN / -1
+
+    use crate::vector2::{IVec2, Vector2};
+    use crate::error::Vector2Error;
+    use crate::space::WorldSpace;
+
+    #[test]
+    fn vector2_should_implement_try_new() {
+        assert_eq!(Ok(Vector2::new(1., 2.)), Vector2::try_new(1., 2.));
+        assert_eq!(Err(Vector2Error::NaN), Vector2::<f32>::try_new(f32::NAN, 2.));
+        assert_eq!(Err(Vector2Error::NaN), Vector2::<f32>::try_new(f32::INFINITY, f32::NAN));
+        assert_eq!(Err(Vector2Error::Infinite), Vector2::<f32>::try_new(1., f32::NEG_INFINITY));
+        assert_eq!(Ok(IVec2::new(1, 2)), IVec2::try_new(1, 2));
+    }
+
+    #[test]
+    fn vector2_should_implement_try_new_in_any_space() {
+        assert_eq!(Ok(Vector2::<f32, WorldSpace>::in_space(1., 2.)), Vector2::try_new(1., 2.));
+        assert_eq!(Err(Vector2Error::NaN), Vector2::<f32, WorldSpace>::try_new(f32::NAN, 2.));
+    }
+
+    #[test]
+    fn vector2_should_implement_is_finite() {
+        assert!(Vector2::new(1., -2.).is_finite());
+        assert!(IVec2::new(i32::MAX, 0).is_finite());
+    }
+
+    #[test]
+    fn vector2_should_implement_is_nan() {
+        assert!(!Vector2::new(1., -2.).is_nan());
+        assert!(!IVec2::new(1, 0).is_nan());
+    }
+
+    #[test]
+    #[cfg(not(feature = "validate"))]
+    fn vector2_should_report_non_finite_components() {
+        assert!(!Vector2::new(1., f32::INFINITY).is_finite());
+        assert!(!Vector2::new(f32::NAN, 0.).is_finite());
+        assert!(Vector2::new(f32::NAN, 0.).is_nan());
+        assert!((Vector2::new(0., 1.) / 0.).is_nan());
+    }
+
+    #[test]
+    fn vector2_should_implement_try_div() {
+        let vec1 = Vector2::new(4., 2.);
+        assert_eq!(Ok(Vector2::new(2., 0.5)), vec1.try_div(&Vector2::new(2., 4.)));
+        assert_eq!(Err(Vector2Error::DivisionByZero), vec1.try_div(&Vector2::new(2., 0.)));
+        assert_eq!(Ok(Vector2::new(2., 1.)), vec1.try_div_k(2.));
+        assert_eq!(Err(Vector2Error::DivisionByZero), vec1.try_div_k(0.));
+        assert_eq!(Err(Vector2Error::DivisionByZero), IVec2::new(4, 2).try_div_k(0));
+    }
+
+    #[test]
+    fn vector2_should_implement_try_rem() {
+        let vec1 = Vector2::new(4., 2.);
+        assert_eq!(Ok(Vector2::new(1., 0.)), vec1.try_rem(&Vector2::new(3., 2.)));
+        assert_eq!(Err(Vector2Error::DivisionByZero), vec1.try_rem(&Vector2::new(0., 2.)));
+        assert_eq!(Ok(Vector2::new(1., 2.)), vec1.try_rem_k(3.));
+        assert_eq!(Err(Vector2Error::DivisionByZero), vec1.try_rem_k(0.));
+    }
+
+    #[test]
+    fn integer_vector2_try_div_and_try_rem_should_report_overflow() {
+        let vec1 = IVec2::new(i32::MIN, 4);
+        assert_eq!(Err(Vector2Error::Overflow), vec1.try_div(&IVec2::new(-1, 2)));
+        assert_eq!(Err(Vector2Error::Overflow), vec1.try_div_k(-1));
+        assert_eq!(Err(Vector2Error::Overflow), vec1.try_rem(&IVec2::new(-1, 3)));
+        assert_eq!(Err(Vector2Error::Overflow), vec1.try_rem_k(-1));
+        assert_eq!(Ok(IVec2::new(i32::MIN / 2, 2)), vec1.try_div_k(2));
+        assert_eq!(Ok(IVec2::new(0, 1)), vec1.try_rem(&IVec2::new(2, 3)));
+    }
+
+    #[test]
+    fn vector2_error_should_implement_display() {
+        assert_eq!("vector division by zero", Vector2Error::DivisionByZero.to_string());
+        assert_eq!("vector component is NaN", Vector2Error::NaN.to_string());
+        assert_eq!("vector arithmetic overflowed", Vector2Error::Overflow.to_string());
+    }
+
+    #[test]
+    #[cfg(all(feature = "validate", debug_assertions))]
+    #[should_panic]
+    fn vector2_should_validate_construction() {
+        Vector2::new(f32::NAN, 0.);
+    }
+
+    #[test]
+    #[cfg(all(feature = "validate", debug_assertions))]
+    #[should_panic]
+    fn vector2_should_validate_setters() {
+        Vector2::new(0., 0.).set_y(f32::INFINITY);
+    }
+}